@@ -0,0 +1,577 @@
+pub mod music;
+pub mod reverb;
+pub mod synth;
+
+use std::collections::HashMap;
+
+use bevy::{
+    audio::{AddAudioSource, AudioSinkPlayback, SpatialScale, Volume},
+    ecs::system::SystemParam,
+    input_focus::InputFocus,
+    prelude::*,
+};
+use serde::Deserialize;
+
+use self::{
+    reverb::{Acoustics, AreaAcoustics},
+    synth::{Muffle, Sound, Synth},
+};
+use crate::{
+    game::{
+        area::{
+            AreaBounds, AreaObjectId, GameEntity, SelectedArea, apply_area_bounds, selected_def,
+        },
+        area_def::{AreaRegistry, InteractKind, Surface},
+        interactables::{InteractionSystems, Lightable},
+        messages::{
+            CrystalActivated, EmberLit, Footfall, Interacted, Jumped, Landed, ObjectiveComplete,
+        },
+        objectives::update_objectives,
+    },
+    input::{Action, Actions},
+    settings::{AppSettings, AudioBus},
+    state::State,
+};
+
+const CUES_PATH: &str = "assets/audio/cues.ron";
+
+// ─── Sound cues ──────────────────────────────────────────────────────────────
+
+/// Everything that plays a sound, as named in `cues.ron`.
+#[derive(Deserialize, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Cue {
+    Footstep(Surface),
+    Jumped,
+    /// Touching down after a jump or fall; louder the harder the landing.
+    Landed(Surface),
+    Interacted,
+    EmberLit,
+    CrystalActivated,
+    ObjectiveComplete,
+    /// Loop on every ember, louder once lit.
+    EmberCrackle,
+    /// Loop on every crystal node, louder once lit.
+    CrystalHum,
+    /// Menu focus moved to another button.
+    MenuMove,
+    MenuConfirm,
+}
+
+/// One entry of the cue table.
+#[derive(Deserialize, Clone, Debug)]
+struct CueDef {
+    /// Takes of the sound; each play picks one at random.
+    sounds: Vec<Synth>,
+    #[serde(default)]
+    bus: AudioBus,
+    #[serde(default = "default_gain")]
+    volume: f32,
+    /// Playback speed; raising it raises the pitch.
+    #[serde(default = "default_gain")]
+    pitch: f32,
+    /// Largest random change to the volume, as a fraction of it.
+    #[serde(default)]
+    volume_variation: f32,
+    /// Largest random change to the pitch, as a fraction of it.
+    #[serde(default)]
+    pitch_variation: f32,
+}
+
+fn default_gain() -> f32 {
+    1.0
+}
+
+/// The cue table.
+#[derive(Resource, Default)]
+struct SoundCues(HashMap<Cue, CueDef>);
+
+/// Small xorshift generator for cue variation.
+#[derive(Resource)]
+struct CueRng(u32);
+
+impl Default for CueRng {
+    fn default() -> Self {
+        Self(0x9E37_79B9)
+    }
+}
+
+impl CueRng {
+    fn next_u32(&mut self) -> u32 {
+        let mut x = self.0;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.0 = x;
+        x
+    }
+
+    /// Next value in -1..=1.
+    fn next_signed(&mut self) -> f32 {
+        self.next_u32() as f32 / u32::MAX as f32 * 2.0 - 1.0
+    }
+
+    /// Random index into a pool of `len` sounds; `len` must not be zero.
+    fn pick(&mut self, len: usize) -> usize {
+        self.next_u32() as usize % len
+    }
+}
+
+/// Plays cues from the table, varied a little every time.
+#[derive(SystemParam)]
+struct CuePlayer<'w, 's> {
+    commands: Commands<'w, 's>,
+    cues: Res<'w, SoundCues>,
+    sounds: ResMut<'w, Assets<Sound>>,
+    acoustics: Res<'w, AreaAcoustics>,
+    rng: ResMut<'w, CueRng>,
+    settings: Res<'w, AppSettings>,
+}
+
+impl CuePlayer<'_, '_> {
+    /// Plays `cue` once, everywhere at once; cues missing from the table are silent.
+    fn play(&mut self, cue: Cue) {
+        self.spawn_once(cue, None, 1.0, 1.0);
+    }
+
+    /// Plays `cue` once from `at`, so it pans and fades with distance from the listener.
+    fn play_at(&mut self, cue: Cue, at: Vec3) {
+        self.spawn_once(cue, Some(at), 1.0, 1.0);
+    }
+
+    /// `level` scales the cue's volume and `tune` its pitch.
+    fn spawn_once(&mut self, cue: Cue, at: Option<Vec3>, level: f32, tune: f32) {
+        let Some((source, mut on_bus, pitch)) = self.vary(cue, tune, false) else { return };
+        on_bus.volume *= level;
+        let gain = self.settings.bus_gain(on_bus.bus);
+        let mut settings = PlaybackSettings::DESPAWN
+            .with_volume(Volume::Linear(on_bus.volume * gain))
+            .with_speed(pitch);
+        let mut entity = self.commands.spawn((on_bus, source));
+        if let Some(at) = at {
+            settings = spatial(settings);
+            entity.insert(Transform::from_translation(at));
+        }
+        entity.insert(settings);
+    }
+
+    /// Looping positional source for `cue`, spawned as a child of the object it
+    /// sounds from. `level` scales the cue's volume and `tune` its pitch.
+    fn emitter(
+        &mut self,
+        cue: Cue,
+        level: f32,
+        tune: f32,
+    ) -> Option<(Emitter, OnBus, AudioPlayer<Sound>, PlaybackSettings, Transform)> {
+        let (source, mut on_bus, pitch) = self.vary(cue, tune, true)?;
+        let emitter = Emitter { volume: on_bus.volume };
+        on_bus.volume *= level;
+        let gain = self.settings.bus_gain(on_bus.bus);
+        let settings = PlaybackSettings::LOOP
+            .with_volume(Volume::Linear(on_bus.volume * gain))
+            .with_speed(pitch);
+        Some((emitter, on_bus, source, spatial(settings), Transform::default()))
+    }
+
+    /// One of the cue's sounds in the area's acoustics, its bus and volume, and
+    /// its pitch times `tune`, each varied per the table. Interface sounds stay dry.
+    fn vary(
+        &mut self,
+        cue: Cue,
+        tune: f32,
+        looping: bool,
+    ) -> Option<(AudioPlayer<Sound>, OnBus, f32)> {
+        let def = self.cues.0.get(&cue).filter(|def| !def.sounds.is_empty())?;
+        let synth = def.sounds[self.rng.pick(def.sounds.len())].clone();
+        let acoustics = self.acoustics.0.filter(|_| def.bus != AudioBus::Ui);
+        let handle = self.sounds.add(Sound { synth, acoustics, looping, muffle: None });
+        let volume = def.volume * (1.0 + def.volume_variation * self.rng.next_signed());
+        let pitch = def.pitch * tune * (1.0 + def.pitch_variation * self.rng.next_signed());
+        let on_bus = OnBus { bus: def.bus, volume: volume.max(0.0) };
+        Some((AudioPlayer(handle), on_bus, pitch.max(0.05)))
+    }
+}
+
+/// World units are metres; spatial sounds are scaled down so the falloff suits
+/// a listener that sits several metres behind the cat.
+const SPATIAL_SCALE: f32 = 0.15;
+
+fn spatial(settings: PlaybackSettings) -> PlaybackSettings {
+    settings.with_spatial(true).with_spatial_scale(SpatialScale::new(SPATIAL_SCALE))
+}
+
+// ─── Buses ───────────────────────────────────────────────────────────────────
+
+/// Mixer bus of a playing sound. Every `AudioPlayer` carries one, so bus
+/// volumes from the settings reach sounds that are already playing.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct OnBus {
+    pub bus: AudioBus,
+    /// The sound's own volume, before the bus and master are applied.
+    pub volume: f32,
+}
+
+/// Sets each sink's volume from its own volume and its bus, whenever either
+/// changes or the sink first appears.
+fn apply_bus_volumes(
+    settings: Res<AppSettings>,
+    mut sinks: Query<(Ref<OnBus>, &mut AudioSink)>,
+    mut spatial_sinks: Query<(Ref<OnBus>, &mut SpatialAudioSink)>,
+) {
+    for (on_bus, mut sink) in &mut sinks {
+        if settings.is_changed() || on_bus.is_changed() || sink.is_added() {
+            sink.set_volume(Volume::Linear(on_bus.volume * settings.bus_gain(on_bus.bus)));
+        }
+    }
+    for (on_bus, mut sink) in &mut spatial_sinks {
+        if settings.is_changed() || on_bus.is_changed() || sink.is_added() {
+            sink.set_volume(Volume::Linear(on_bus.volume * settings.bus_gain(on_bus.bus)));
+        }
+    }
+}
+
+// ─── Emitters ────────────────────────────────────────────────────────────────
+
+/// Share of its volume an emitter keeps while its object is unlit.
+const UNLIT_EMITTER: f32 = 0.35;
+
+/// Looping positional sound on an ember or crystal node, as a child of it.
+#[derive(Component)]
+struct Emitter {
+    /// Volume once the object is lit.
+    volume: f32,
+}
+
+fn emitter_level(lightable: &Lightable) -> f32 {
+    if lightable.lit { 1.0 } else { UNLIT_EMITTER }
+}
+
+/// Steps of a major pentatonic scale, as pitch ratios. Crystal nodes take them in
+/// turn, so each one hums and chimes on its own note and any set of them is in tune.
+const CRYSTAL_SCALE: [f32; 5] = [1.0, 9.0 / 8.0, 5.0 / 4.0, 3.0 / 2.0, 5.0 / 3.0];
+
+fn crystal_tuning(id: Option<&AreaObjectId>) -> f32 {
+    id.map_or(1.0, |id| CRYSTAL_SCALE[id.0 % CRYSTAL_SCALE.len()])
+}
+
+/// Gives every new ember its crackle and every crystal node its hum.
+fn attach_emitters(
+    mut player: CuePlayer,
+    lightables: Query<(Entity, &Lightable, Option<&AreaObjectId>), Added<Lightable>>,
+) {
+    for (entity, lightable, id) in &lightables {
+        let (cue, tune) = match lightable.kind {
+            InteractKind::Crystal => (Cue::CrystalHum, crystal_tuning(id)),
+            _ => (Cue::EmberCrackle, 1.0),
+        };
+        if let Some(emitter) = player.emitter(cue, emitter_level(lightable), tune) {
+            player.commands.entity(entity).with_child(emitter);
+        }
+    }
+}
+
+/// Swells an emitter to full volume once its object is lit.
+fn sync_emitters(
+    lightables: Query<(&Lightable, &Children), Changed<Lightable>>,
+    mut emitters: Query<(&Emitter, &mut OnBus)>,
+) {
+    for (lightable, children) in &lightables {
+        for &child in children.iter() {
+            if let Ok((emitter, mut on_bus)) = emitters.get_mut(child) {
+                on_bus.volume = emitter.volume * emitter_level(lightable);
+            }
+        }
+    }
+}
+
+/// Emitters would keep crackling under the pause menu, so they stop with the world.
+fn pause_emitters(sinks: Query<&SpatialAudioSink, With<Emitter>>) {
+    for sink in &sinks {
+        sink.pause();
+    }
+}
+
+fn resume_emitters(sinks: Query<&SpatialAudioSink, With<Emitter>>) {
+    for sink in &sinks {
+        sink.play();
+    }
+}
+
+// ─── Ambience ────────────────────────────────────────────────────────────────
+
+/// Seconds for an ambient bed to fade fully in or out.
+const AMBIENCE_FADE_SECONDS: f32 = 1.5;
+
+/// Share of its volume an ambient bed keeps while the game is paused; it is
+/// muffled by a low-pass as well.
+const PAUSED_AMBIENCE: f32 = 0.3;
+
+/// Looping ambience of one area. It is a `GameEntity`, so it goes with the
+/// rest of the area, but a portal swap leaves it playing so it can fade out
+/// under the next area's bed.
+#[derive(Component)]
+pub struct AmbientBed {
+    area: String,
+    volume: f32,
+    /// Current fade level in 0..=1, multiplied into `volume`.
+    level: f32,
+    /// How far into the pause duck the bed is, in 0..=1, and the low-pass it drives.
+    duck: f32,
+    muffle: Muffle,
+    leaving: bool,
+}
+
+/// Set while paused, so the beds duck.
+#[derive(Resource, Default)]
+struct AmbienceDucked(bool);
+
+// ─── Setup ───────────────────────────────────────────────────────────────────
+
+/// Reads the cue table.
+fn load_sound_cues(mut commands: Commands) {
+    let parsed = std::fs::read_to_string(CUES_PATH)
+        .map_err(|e| e.to_string())
+        .and_then(|s| ron::from_str::<HashMap<Cue, CueDef>>(&s).map_err(|e| e.to_string()));
+    let cues = parsed.unwrap_or_else(|e| {
+        warn!("No sound cues from {CUES_PATH}: {e}");
+        HashMap::new()
+    });
+    commands.insert_resource(SoundCues(cues));
+}
+
+/// Works out the selected area's acoustics from the size of its camera bounds
+/// and any overrides in its definition.
+fn apply_area_acoustics(
+    selected: Res<SelectedArea>,
+    registry: Res<AreaRegistry>,
+    bounds: Res<AreaBounds>,
+    mut acoustics: ResMut<AreaAcoustics>,
+) {
+    let room = Acoustics::for_room(&bounds.camera);
+    let overrides = selected_def(&registry, &selected).map(|d| d.acoustics).unwrap_or_default();
+    acoustics.0 = Some(room.with(&overrides));
+}
+
+/// Starts the selected area's ambient bed, silent, and sends every other bed
+/// fading out. Resuming in the same area keeps its bed playing untouched.
+fn sync_ambience(
+    mut commands: Commands,
+    selected: Res<SelectedArea>,
+    registry: Res<AreaRegistry>,
+    acoustics: Res<AreaAcoustics>,
+    mut sounds: ResMut<Assets<Sound>>,
+    mut beds: Query<&mut AmbientBed>,
+) {
+    let mut playing = false;
+    for mut bed in &mut beds {
+        if bed.area == selected.0 && !bed.leaving {
+            playing = true;
+        } else {
+            bed.leaving = true;
+        }
+    }
+    if playing {
+        return;
+    }
+    let Some(ambience) = selected_def(&registry, &selected).and_then(|d| d.ambience.as_ref()) else {
+        return;
+    };
+    let muffle = Muffle::default();
+    commands.spawn((
+        GameEntity,
+        AmbientBed {
+            area: selected.0.clone(),
+            volume: ambience.volume,
+            level: 0.0,
+            duck: 0.0,
+            muffle: muffle.clone(),
+            leaving: false,
+        },
+        OnBus { bus: AudioBus::Ambience, volume: 0.0 },
+        AudioPlayer(sounds.add(Sound {
+            synth: ambience.sound.clone(),
+            acoustics: acoustics.0,
+            looping: true,
+            muffle: Some(muffle),
+        })),
+        // The muffled sound loops itself
+        PlaybackSettings::ONCE.with_volume(Volume::Linear(0.0)),
+    ));
+}
+
+fn duck_ambience(mut ducked: ResMut<AmbienceDucked>) {
+    ducked.0 = true;
+}
+
+fn unduck_ambience(mut ducked: ResMut<AmbienceDucked>) {
+    ducked.0 = false;
+}
+
+/// Moves every bed toward its level — full, ducked or silent — sweeps the
+/// low-pass with the duck, and drops beds that have faded out.
+fn fade_ambience(
+    mut commands: Commands,
+    time: Res<Time>,
+    ducked: Res<AmbienceDucked>,
+    mut beds: Query<(Entity, &mut AmbientBed, &mut OnBus)>,
+) {
+    let step = time.delta_secs() / AMBIENCE_FADE_SECONDS;
+    for (entity, mut bed, mut on_bus) in &mut beds {
+        let target = if bed.leaving {
+            0.0
+        } else if ducked.0 {
+            PAUSED_AMBIENCE
+        } else {
+            1.0
+        };
+        bed.level = if bed.level < target {
+            (bed.level + step).min(target)
+        } else {
+            (bed.level - step).max(target)
+        };
+        if bed.leaving && bed.level <= 0.0 {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        let duck = if ducked.0 && !bed.leaving { 1.0 } else { 0.0 };
+        if bed.duck != duck {
+            bed.duck = if bed.duck < duck {
+                (bed.duck + step).min(duck)
+            } else {
+                (bed.duck - step).max(duck)
+            };
+            bed.muffle.set(bed.duck);
+        }
+        let volume = bed.volume * bed.level;
+        if on_bus.volume != volume {
+            on_bus.volume = volume;
+        }
+    }
+}
+
+// ─── Systems ─────────────────────────────────────────────────────────────────
+
+/// Downward speed at which a landing plays at full volume, in metres per second.
+const HARD_LANDING: f32 = 10.0;
+/// Share of the landing volume kept by the softest touchdown.
+const SOFT_LANDING: f32 = 0.2;
+
+/// Plays a step on the surface underfoot each time the gait puts paws down, and
+/// a landing scaled by how fast the cat came down.
+fn play_footsteps(
+    mut player: CuePlayer,
+    transforms: Query<&GlobalTransform>,
+    mut footfalls: MessageReader<Footfall>,
+    mut landed: MessageReader<Landed>,
+) {
+    for footfall in footfalls.read() {
+        let Ok(paws) = transforms.get(footfall.cat) else { continue };
+        player.play_at(Cue::Footstep(footfall.surface), paws.translation());
+    }
+    for landing in landed.read() {
+        let Ok(paws) = transforms.get(landing.cat) else { continue };
+        let level = (landing.impact / HARD_LANDING).clamp(SOFT_LANDING, 1.0);
+        player.spawn_once(Cue::Landed(landing.surface), Some(paws.translation()), level, 1.0);
+    }
+}
+
+/// Plays the cue for every gameplay message sent this frame, from the entity it
+/// concerns. Entities already gone, like a collected secret, play unpositioned.
+/// An interaction with its own cue, like lighting an ember, skips the generic one.
+fn play_gameplay_cues(
+    mut player: CuePlayer,
+    transforms: Query<&GlobalTransform>,
+    ids: Query<&AreaObjectId>,
+    mut jumped: MessageReader<Jumped>,
+    mut interacted: MessageReader<Interacted>,
+    mut embers_lit: MessageReader<EmberLit>,
+    mut crystals_activated: MessageReader<CrystalActivated>,
+    mut objectives_complete: MessageReader<ObjectiveComplete>,
+) {
+    let mut specific = Vec::new();
+    for message in crystals_activated.read() {
+        // Chimes on the node's own note, the one it hums
+        let at = transforms.get(message.target).ok().map(|t| t.translation());
+        let tune = crystal_tuning(ids.get(message.target).ok());
+        player.spawn_once(Cue::CrystalActivated, at, 1.0, tune);
+        specific.push(message.target);
+    }
+    let mut play_from = |cue: Cue, entity: Entity| match transforms.get(entity) {
+        Ok(transform) => player.play_at(cue, transform.translation()),
+        Err(_) => player.play(cue),
+    };
+    for message in jumped.read() {
+        play_from(Cue::Jumped, message.cat);
+    }
+    for message in embers_lit.read() {
+        play_from(Cue::EmberLit, message.target);
+        specific.push(message.target);
+    }
+    for message in interacted.read() {
+        if !specific.contains(&message.target) {
+            play_from(Cue::Interacted, message.target);
+        }
+    }
+    for _ in objectives_complete.read() {
+        player.play(Cue::ObjectiveComplete);
+    }
+}
+
+/// Ticks as menu focus moves and clicks when a menu button is pressed.
+fn play_menu_cues(
+    mut player: CuePlayer,
+    actions: Actions,
+    input_focus: Res<InputFocus>,
+    mut last_focus: Local<Option<Entity>>,
+    pressed: Query<&Interaction, (Changed<Interaction>, With<Button>)>,
+) {
+    if input_focus.0.is_some() && input_focus.0 != *last_focus {
+        player.play(Cue::MenuMove);
+    }
+    *last_focus = input_focus.0;
+
+    let confirmed = actions.just_pressed(Action::MenuConfirm) && input_focus.0.is_some();
+    if confirmed || pressed.iter().any(|i| *i == Interaction::Pressed) {
+        player.play(Cue::MenuConfirm);
+    }
+}
+
+// ─── Plugin ──────────────────────────────────────────────────────────────────
+
+pub struct AudioPlugin;
+
+impl Plugin for AudioPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_audio_source::<Sound>()
+            .init_resource::<CueRng>()
+            .init_resource::<AreaAcoustics>()
+            .init_resource::<AmbienceDucked>()
+            .add_systems(Startup, load_sound_cues)
+            .add_systems(
+                OnEnter(State::Playing),
+                (
+                    apply_area_acoustics.after(apply_area_bounds),
+                    sync_ambience.after(apply_area_acoustics),
+                ),
+            )
+            .add_systems(OnEnter(State::Paused), (duck_ambience, pause_emitters))
+            .add_systems(OnExit(State::Paused), (unduck_ambience, resume_emitters))
+            .add_systems(
+                Update,
+                (fade_ambience, apply_bus_volumes.after(fade_ambience))
+                    .run_if(resource_exists::<AppSettings>),
+            )
+            .add_systems(Update, play_menu_cues.run_if(not(in_state(State::Playing))))
+            .add_systems(
+                Update,
+                (
+                    play_footsteps,
+                    attach_emitters,
+                    sync_emitters.after(InteractionSystems::Behaviours),
+                    play_gameplay_cues
+                        .after(InteractionSystems::Behaviours)
+                        .after(update_objectives),
+                )
+                    .run_if(in_state(State::Playing)),
+            );
+    }
+}
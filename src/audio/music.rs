@@ -0,0 +1,269 @@
+use bevy::{audio::Volume, prelude::*, state::state::State as Current};
+
+use super::{
+    OnBus, apply_bus_volumes,
+    synth::{Sound, Synth},
+};
+use crate::{
+    game::{
+        area::{SelectedArea, selected_def},
+        area_def::{AreaRegistry, MusicDef},
+        objectives::{Objectives, update_objectives},
+    },
+    settings::{AppSettings, AudioBus},
+    state::State,
+};
+
+const MENU_MUSIC_PATH: &str = "assets/audio/menu_music.ron";
+
+/// Seconds for a whole score to fade in or out when another takes over.
+const MUSIC_FADE_SECONDS: f32 = 2.0;
+
+/// Share of its volume the score keeps while the game is paused.
+const PAUSED_MUSIC: f32 = 0.4;
+
+// ─── Components ──────────────────────────────────────────────────────────────
+
+/// Which score belongs with the current screen.
+#[derive(Clone, PartialEq, Eq, Debug)]
+enum Score {
+    Menu,
+    Area(String),
+}
+
+/// One score playing, parent of its stems. Leaving scores fade out under the
+/// next one and are dropped once silent.
+#[derive(Component)]
+struct Music {
+    score: Score,
+    /// Seconds per beat and beats per bar.
+    beat: f32,
+    beats_per_bar: u32,
+    /// Seconds since the stems started, to find the beat by.
+    elapsed: f32,
+    /// Share of objective progress the layers follow, 0..1.
+    share: f32,
+    stinger: Option<Synth>,
+    finale: Option<Synth>,
+    /// Waiting for the next beat and the next bar respectively.
+    stinger_due: bool,
+    finale_due: bool,
+    /// Current fade level in 0..=1, multiplied into every stem.
+    level: f32,
+    leaving: bool,
+}
+
+/// One looping layer of a score.
+#[derive(Component)]
+struct Stem {
+    volume: f32,
+    /// Share of objective progress at which the layer comes in.
+    from: f32,
+    /// Current fade level in 0..=1, moving toward `target` over a bar.
+    layer: f32,
+    target: f32,
+}
+
+/// Score for the menus, read at startup.
+#[derive(Resource, Default)]
+struct MenuMusic(Option<MusicDef>);
+
+// ─── Setup ───────────────────────────────────────────────────────────────────
+
+fn load_menu_music(mut commands: Commands) {
+    let parsed = std::fs::read_to_string(MENU_MUSIC_PATH)
+        .map_err(|e| e.to_string())
+        .and_then(|s| ron::from_str::<MusicDef>(&s).map_err(|e| e.to_string()));
+    let def = parsed.map_err(|e| warn!("No menu music from {MENU_MUSIC_PATH}: {e}")).ok();
+    commands.insert_resource(MenuMusic(def));
+}
+
+// ─── Systems ─────────────────────────────────────────────────────────────────
+
+/// Starts the score for the current screen — the menu theme in the menus, the
+/// selected area's score in play — and sends every other score fading out.
+/// Loading keeps whatever is playing.
+fn sync_score(
+    mut commands: Commands,
+    state: Res<Current<State>>,
+    selected: Res<SelectedArea>,
+    registry: Res<AreaRegistry>,
+    menu: Res<MenuMusic>,
+    mut sounds: ResMut<Assets<Sound>>,
+    mut scores: Query<&mut Music>,
+) {
+    let wanted = match state.get() {
+        State::Loading => return,
+        State::MainMenu | State::OptionsMenu | State::ControlsMenu | State::ChooseArea => {
+            Score::Menu
+        }
+        State::Playing | State::Paused | State::Travelling | State::AreaComplete => {
+            Score::Area(selected.0.clone())
+        }
+    };
+    let mut playing = false;
+    for mut music in &mut scores {
+        if music.score == wanted && !music.leaving {
+            playing = true;
+        } else if !music.leaving {
+            music.leaving = true;
+        }
+    }
+    if playing {
+        return;
+    }
+    let def = match &wanted {
+        Score::Menu => menu.0.as_ref(),
+        Score::Area(_) => selected_def(&registry, &selected).and_then(|d| d.music.as_ref()),
+    };
+    let Some(def) = def else { return };
+    commands
+        .spawn(Music {
+            score: wanted,
+            beat: 60.0 / def.tempo.max(1.0),
+            beats_per_bar: def.beats_per_bar.max(1),
+            elapsed: 0.0,
+            share: 0.0,
+            stinger: def.stinger.clone(),
+            finale: def.finale.clone(),
+            stinger_due: false,
+            finale_due: false,
+            level: 0.0,
+            leaving: false,
+        })
+        .with_children(|score| {
+            // All stems start together, so they stay in step for as long as they loop
+            for stem in &def.stems {
+                score.spawn((
+                    Stem { volume: stem.volume, from: stem.from, layer: 0.0, target: 0.0 },
+                    OnBus { bus: AudioBus::Music, volume: 0.0 },
+                    AudioPlayer(sounds.add(Sound {
+                        synth: stem.sound.clone(),
+                        acoustics: None,
+                        looping: true,
+                        muffle: None,
+                    })),
+                    PlaybackSettings::LOOP.with_volume(Volume::Linear(0.0)),
+                ));
+            }
+        });
+}
+
+/// Follows objective progress in the area's score, and queues a stinger for
+/// each step forward or the finale once everything is done.
+fn follow_objectives(objectives: Objectives, mut scores: Query<&mut Music>) {
+    let advanced = objectives.advanced();
+    let share = objectives.share();
+    for mut music in &mut scores {
+        if music.leaving || !matches!(music.score, Score::Area(_)) {
+            continue;
+        }
+        if music.share != share {
+            music.share = share;
+        }
+        if advanced && objectives.all_complete() {
+            music.finale_due = true;
+        } else if advanced {
+            music.stinger_due = true;
+        }
+    }
+}
+
+/// Keeps time for every score. Layers change and stingers play on the beat,
+/// the finale on the bar; each layer then fades over a bar, and whole scores
+/// fade in and out as they take over from each other.
+fn play_music(
+    mut commands: Commands,
+    time: Res<Time>,
+    state: Res<Current<State>>,
+    settings: Res<AppSettings>,
+    mut sounds: ResMut<Assets<Sound>>,
+    mut scores: Query<(Entity, &mut Music, Option<&Children>)>,
+    mut stems: Query<(&mut Stem, &mut OnBus)>,
+) {
+    let dt = time.delta_secs();
+    for (entity, mut music, children) in &mut scores {
+        let bar = music.beat * music.beats_per_bar as f32;
+        let before = music.elapsed;
+        music.elapsed += dt;
+        let on_beat = (music.elapsed / music.beat).floor() > (before / music.beat).floor();
+        let on_bar = (music.elapsed / bar).floor() > (before / bar).floor();
+
+        let mut cues = Vec::new();
+        if on_beat && std::mem::take(&mut music.stinger_due) {
+            cues.extend(music.stinger.clone());
+        }
+        if on_bar && std::mem::take(&mut music.finale_due) {
+            cues.extend(music.finale.clone());
+        }
+
+        let target = if music.leaving {
+            0.0
+        } else if *state.get() == State::Paused {
+            PAUSED_MUSIC
+        } else {
+            1.0
+        };
+        let step = dt / MUSIC_FADE_SECONDS;
+        music.level = if music.level < target {
+            (music.level + step).min(target)
+        } else {
+            (music.level - step).max(target)
+        };
+        if music.leaving && music.level <= 0.0 {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        for synth in cues {
+            let volume = music.level;
+            commands.spawn((
+                OnBus { bus: AudioBus::Music, volume },
+                AudioPlayer(sounds.add(Sound { synth, acoustics: None, looping: false, muffle: None })),
+                PlaybackSettings::DESPAWN
+                    .with_volume(Volume::Linear(volume * settings.bus_gain(AudioBus::Music))),
+            ));
+        }
+
+        let layer_step = dt / bar.max(f32::EPSILON);
+        for &child in children.into_iter().flatten() {
+            let Ok((mut stem, mut on_bus)) = stems.get_mut(child) else { continue };
+            // The first beat sets the layers a resumed area has already earned
+            if on_beat {
+                stem.target = if music.share >= stem.from { 1.0 } else { 0.0 };
+            }
+            stem.layer = if stem.layer < stem.target {
+                (stem.layer + layer_step).min(stem.target)
+            } else {
+                (stem.layer - layer_step).max(stem.target)
+            };
+            let volume = stem.volume * stem.layer * music.level;
+            if on_bus.volume != volume {
+                on_bus.volume = volume;
+            }
+        }
+    }
+}
+
+// ─── Plugin ──────────────────────────────────────────────────────────────────
+
+pub struct MusicPlugin;
+
+impl Plugin for MusicPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MenuMusic>()
+            .add_systems(Startup, load_menu_music)
+            .add_systems(
+                Update,
+                (
+                    sync_score,
+                    follow_objectives.after(update_objectives),
+                    play_music
+                        .after(sync_score)
+                        .after(follow_objectives)
+                        .before(apply_bus_volumes),
+                )
+                    .run_if(resource_exists::<AppSettings>),
+            );
+    }
+}
@@ -0,0 +1,187 @@
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+
+use super::synth::SAMPLE_RATE;
+use crate::game::{area_def::AcousticsDef, boundary::Boundary};
+
+/// Speed of sound in air, in metres per second.
+const SPEED_OF_SOUND: f32 = 343.0;
+
+/// Early reflections as (delay, gain): the delay is a multiple of the first
+/// reflection's, each later bounce arriving quieter.
+const REFLECTIONS: [(f32, f32); 4] = [(1.0, 0.6), (1.4, 0.45), (1.9, 0.35), (2.6, 0.25)];
+
+/// Delays of the parallel comb filters that build the late reverb, in samples.
+/// Mutually prime, so their echoes don't pile up on the same samples.
+const COMBS: [usize; 4] = [1116, 1188, 1277, 1356];
+
+/// Delays of the allpass filters that smear the combs' echoes, in samples.
+const ALLPASSES: [usize; 2] = [556, 441];
+const ALLPASS_GAIN: f32 = 0.5;
+
+/// How an area colours the sounds played in it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Acoustics {
+    /// Seconds for the reverb to die away by 60 dB.
+    pub reverb_seconds: f32,
+    /// Seconds before the first reflection off the walls arrives.
+    pub reflections: f32,
+    /// Share of reverberant sound in the mix, 0..1.
+    pub wet: f32,
+    /// Cutoff of the low-pass on the reverb, in Hz — walls soak up the highs.
+    pub low_pass: f32,
+}
+
+impl Acoustics {
+    /// Typical acoustics for a room enclosed by `walls`: larger rooms ring
+    /// longer, echo later, sound wetter and duller.
+    pub fn for_room(walls: &Boundary) -> Self {
+        // Radius of a circle of the same floor area
+        let radius = (walls.area() / std::f32::consts::PI).sqrt();
+        Self {
+            reverb_seconds: (radius * 0.12).clamp(0.3, 3.0),
+            reflections: 2.0 * radius / SPEED_OF_SOUND,
+            wet: (radius / 40.0).clamp(0.1, 0.45),
+            low_pass: (10_000.0 - 350.0 * radius).max(2_000.0),
+        }
+    }
+
+    /// These acoustics with the fields set in `overrides` replaced.
+    pub fn with(self, overrides: &AcousticsDef) -> Self {
+        Self {
+            reverb_seconds: overrides.reverb_seconds.unwrap_or(self.reverb_seconds),
+            reflections: overrides.reflections.unwrap_or(self.reflections),
+            wet: overrides.wet.unwrap_or(self.wet),
+            low_pass: overrides.low_pass.unwrap_or(self.low_pass),
+        }
+    }
+
+    /// Mixes `dry` with its reverb in this room. One-shot sounds grow a tail as
+    /// long as the reverb; a `looping` sound wraps the tail round onto its start
+    /// instead, as if the previous pass of the loop were still ringing.
+    pub fn apply(&self, dry: &[f32], looping: bool) -> Vec<f32> {
+        if dry.is_empty() || self.wet <= 0.0 {
+            return dry.to_vec();
+        }
+        let rate = SAMPLE_RATE as f32;
+        let len = dry.len() + (self.reverb_seconds.max(0.0) * rate) as usize;
+        let input = |n: usize| dry.get(n).copied().unwrap_or(0.0);
+
+        // Early reflections: a few discrete echoes off the nearest walls
+        let first = (self.reflections.max(0.0) * rate) as usize;
+        let mut wet = vec![0.0; len];
+        for (scale, gain) in REFLECTIONS {
+            let delay = (first as f32 * scale) as usize;
+            for (n, &sample) in dry.iter().enumerate() {
+                if let Some(out) = wet.get_mut(n + delay) {
+                    *out += sample * gain;
+                }
+            }
+        }
+
+        // Late reverb: parallel combs after the first reflection, each fed back
+        // hard enough to fall 60 dB in `reverb_seconds`
+        let decay_seconds = self.reverb_seconds.max(0.01);
+        let mut late = vec![0.0; len];
+        for delay in COMBS {
+            let feedback = 10.0_f32.powf(-3.0 * delay as f32 / rate / decay_seconds);
+            let mut comb = vec![0.0; len];
+            for n in delay..len {
+                let source = (n - delay).checked_sub(first).map_or(0.0, input);
+                comb[n] = source + feedback * comb[n - delay];
+            }
+            for (out, sample) in late.iter_mut().zip(&comb) {
+                *out += sample / COMBS.len() as f32;
+            }
+        }
+        for delay in ALLPASSES {
+            let mut out = vec![0.0; len];
+            for n in 0..len {
+                let delayed_in = if n >= delay { late[n - delay] } else { 0.0 };
+                let delayed_out = if n >= delay { out[n - delay] } else { 0.0 };
+                out[n] = -ALLPASS_GAIN * late[n] + delayed_in + ALLPASS_GAIN * delayed_out;
+            }
+            late = out;
+        }
+
+        // Damp the highs of everything reflected, then mix
+        let smoothing = 1.0 - (-TAU * self.low_pass / rate).exp();
+        let mut damped = 0.0;
+        let mut mixed: Vec<f32> = (0..len)
+            .map(|n| {
+                damped += smoothing * (wet[n] + late[n] - damped);
+                (input(n) * (1.0 - self.wet) + damped * self.wet).clamp(-1.0, 1.0)
+            })
+            .collect();
+
+        if looping {
+            let tail = mixed.split_off(dry.len());
+            for (n, sample) in tail.into_iter().enumerate() {
+                let wrapped = &mut mixed[n % dry.len()];
+                *wrapped = (*wrapped + sample).clamp(-1.0, 1.0);
+            }
+        }
+        mixed
+    }
+}
+
+/// Acoustics of the selected area, worked out as play starts; `None` keeps
+/// every sound dry.
+#[derive(Resource, Default)]
+pub struct AreaAcoustics(pub Option<Acoustics>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn room() -> Acoustics {
+        Acoustics { reverb_seconds: 0.5, reflections: 0.02, wet: 0.3, low_pass: 6000.0 }
+    }
+
+    /// A short burst, then silence.
+    fn click() -> Vec<f32> {
+        let mut dry = vec![0.0; SAMPLE_RATE as usize / 2];
+        dry[..32].fill(0.8);
+        dry
+    }
+
+    #[test]
+    fn one_shot_grows_a_reverb_tail() {
+        let dry = click();
+        let wet = room().apply(&dry, false);
+        let tail = (room().reverb_seconds * SAMPLE_RATE as f32) as usize;
+        assert_eq!(wet.len(), dry.len() + tail);
+        assert!(wet.iter().all(|s| s.is_finite() && s.abs() <= 1.0));
+        // The echo of the click is still ringing after the dry sound is over
+        assert!(wet[dry.len() / 2..].iter().any(|s| s.abs() > 1e-4));
+    }
+
+    #[test]
+    fn loop_keeps_its_length() {
+        let dry = click();
+        let wet = room().apply(&dry, true);
+        assert_eq!(wet.len(), dry.len());
+        assert!(wet.iter().all(|s| s.is_finite() && s.abs() <= 1.0));
+    }
+
+    #[test]
+    fn loop_wraps_the_tail_onto_its_start() {
+        let dry = click();
+        let one_shot = room().apply(&dry, false);
+        let looped = room().apply(&dry, true);
+        // Past the click, the start of the loop hears the previous pass ringing
+        let n = 64;
+        let expected = (one_shot[n] + one_shot[dry.len() + n]).clamp(-1.0, 1.0);
+        assert!((looped[n] - expected).abs() < 1e-6);
+        assert!(one_shot[dry.len() + n].abs() > 0.0);
+    }
+
+    #[test]
+    fn dry_room_leaves_the_sound_alone() {
+        let dry = click();
+        let acoustics = Acoustics { wet: 0.0, ..room() };
+        assert_eq!(acoustics.apply(&dry, false), dry);
+        assert_eq!(acoustics.apply(&dry, true), dry);
+    }
+}
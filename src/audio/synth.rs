@@ -0,0 +1,573 @@
+use std::{
+    f32::consts::TAU,
+    sync::{
+        Arc,
+        atomic::{AtomicU32, Ordering},
+    },
+    time::Duration,
+};
+
+use bevy::{
+    audio::{Decodable, Source},
+    prelude::*,
+};
+use serde::Deserialize;
+
+use super::reverb::Acoustics;
+
+/// Samples per second of every synthesized sound.
+pub const SAMPLE_RATE: u32 = 44_100;
+
+/// Loudest sample of a rendered sound; each one is scaled to peak here.
+const PEAK: f32 = 0.85;
+
+/// Seconds a drone fades in and out at its ends.
+const DRONE_FADE: f32 = 0.15;
+
+/// Ignition is a burst of crackle, then a warm tone swelling in.
+const IGNITION_CRACKLE: f32 = 0.3;
+const IGNITION_TONE: f32 = 0.2;
+/// Chance per sample of a pop in the ignition crackle.
+const IGNITION_POPS: f32 = 0.03;
+
+/// Chance per sample of a pop in a looping crackle, and seconds for one to ring out.
+const CRACKLE_POPS: f32 = 0.0015;
+const POP_RING: f32 = 0.02;
+
+/// Pitch ratio of the sharp copy of each pad note.
+const PAD_DETUNE: f32 = 1.004;
+
+/// Pitch of the thump under every pulse step, in Hz.
+const PULSE_THUMP: f32 = 60.0;
+
+/// Step between the seeds of successive plays.
+const SEED_STEP: u32 = 0x9E37_79B9;
+
+/// Seeds for sounds as they play, so no two noise bursts are the same.
+static NEXT_SEED: AtomicU32 = AtomicU32::new(0x2545_F491);
+
+// ─── Parameters ──────────────────────────────────────────────────────────────
+
+/// A sound synthesized from parameters when it starts playing, as written in
+/// `cues.ron` and the area files. Frequencies are in Hz, lengths in seconds.
+#[derive(Deserialize, Clone, Debug)]
+pub enum Synth {
+    /// Low drone: a root and three harmonics under a slow tremolo and faint hiss.
+    Drone { root: f32, seconds: f32 },
+    /// Band of noise from `low` to `high` over a dull thump, both dying away fast.
+    /// A `thump` of 0.0 leaves only the noise.
+    Thud { seconds: f32, low: f32, high: f32, thump: f32 },
+    /// Bright tick, then glassy partials ringing out.
+    Bell { seconds: f32, tone: f32 },
+    /// Hollow knock from two body resonances, with a little grain.
+    Knock { seconds: f32, tone: f32 },
+    /// Sine gliding between two pitches as it fades.
+    Sweep { seconds: f32, from: f32, to: f32 },
+    /// Crackle of something catching fire, settling into a warm tone.
+    Ignition { tone: f32 },
+    /// Notes one after another, each with two overtones.
+    Fanfare { notes: Vec<f32>, note_seconds: f32 },
+    /// Low fire roar with sparse bright pops, for looping.
+    Crackle { seconds: f32 },
+    /// Glassy partials beating slowly, for looping. The root is rounded so every
+    /// partial completes whole cycles and the loop has no seam.
+    Hum { root: f32, seconds: f32 },
+    /// Music: slow-swelling chords of softly detuned pairs, one chord per
+    /// `chord_seconds`.
+    Pad { chords: Vec<Vec<f32>>, chord_seconds: f32 },
+    /// Music: plucked notes that ring out; a 0.0 note is a rest.
+    Pluck { notes: Vec<f32>, note_seconds: f32 },
+    /// Music: round bass notes with a touch of the octave; a 0.0 note is a rest.
+    Bass { notes: Vec<f32>, note_seconds: f32 },
+    /// Music: soft percussion, a tick over a low thump at each step's level.
+    /// A 0.0 step is silent.
+    Pulse { steps: Vec<f32>, step_seconds: f32 },
+}
+
+impl Synth {
+    /// Length of the sound in seconds.
+    pub fn seconds(&self) -> f32 {
+        match self {
+            Synth::Drone { seconds, .. }
+            | Synth::Thud { seconds, .. }
+            | Synth::Bell { seconds, .. }
+            | Synth::Knock { seconds, .. }
+            | Synth::Sweep { seconds, .. }
+            | Synth::Crackle { seconds }
+            | Synth::Hum { seconds, .. } => *seconds,
+            Synth::Ignition { .. } => IGNITION_CRACKLE + IGNITION_TONE,
+            Synth::Fanfare { notes, note_seconds }
+            | Synth::Pluck { notes, note_seconds }
+            | Synth::Bass { notes, note_seconds } => notes.len() as f32 * note_seconds,
+            Synth::Pad { chords, chord_seconds } => chords.len() as f32 * chord_seconds,
+            Synth::Pulse { steps, step_seconds } => steps.len() as f32 * step_seconds,
+        }
+    }
+
+    /// Renders the whole sound at `SAMPLE_RATE`, mono. The same parameters and
+    /// `seed` always give the same samples, so a sound can be checked without
+    /// an audio device.
+    pub fn render(&self, seed: u32) -> Vec<f32> {
+        let len = (self.seconds().max(0.0) * SAMPLE_RATE as f32) as usize;
+        let mut voice = Voice::new(self, len, seed);
+        let mut samples: Vec<f32> = (0..len).map(|index| voice.sample(index)).collect();
+        let peak = samples.iter().fold(0.0_f32, |peak, s| peak.max(s.abs()));
+        if peak > 0.0 {
+            for sample in &mut samples {
+                *sample *= PEAK / peak;
+            }
+        }
+        samples
+    }
+
+    /// Noise bands the voice filters: its main band, and the band pops ring
+    /// through in a crackle.
+    fn bands(&self) -> [Option<(f32, f32)>; 2] {
+        match *self {
+            Synth::Drone { .. } => [Some((2000.0, 6000.0)), None],
+            Synth::Thud { low, high, .. } => [Some((low, high)), None],
+            Synth::Bell { .. } => [Some((2500.0, 8000.0)), None],
+            Synth::Knock { .. } => [Some((600.0, 2500.0)), None],
+            Synth::Ignition { .. } => [Some((400.0, 3000.0)), None],
+            Synth::Crackle { .. } => [Some((80.0, 400.0)), Some((1000.0, 4500.0))],
+            Synth::Pulse { .. } => [Some((3000.0, 9000.0)), None],
+            Synth::Sweep { .. }
+            | Synth::Fanfare { .. }
+            | Synth::Hum { .. }
+            | Synth::Pad { .. }
+            | Synth::Pluck { .. }
+            | Synth::Bass { .. } => [None, None],
+        }
+    }
+}
+
+// ─── Voice ───────────────────────────────────────────────────────────────────
+
+/// Generator state while rendering one `Synth`.
+struct Voice<'a> {
+    synth: &'a Synth,
+    len: usize,
+    /// Xorshift state for noise.
+    noise: u32,
+    band: BandPass,
+    pop_band: BandPass,
+    /// Oscillator phase in radians, for a pitch that glides.
+    phase: f32,
+    /// Level of the pop ringing out in a crackle.
+    pop: f32,
+}
+
+impl<'a> Voice<'a> {
+    fn new(synth: &'a Synth, len: usize, seed: u32) -> Self {
+        let [band, pop_band] = synth.bands().map(|b| b.map_or_else(BandPass::default, BandPass::new));
+        Self { synth, len, noise: seed | 1, band, pop_band, phase: 0.0, pop: 0.0 }
+    }
+
+    /// Next value in 0..1.
+    fn unit(&mut self) -> f32 {
+        let mut x = self.noise;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.noise = x;
+        x as f32 / u32::MAX as f32
+    }
+
+    /// White noise with unit variance.
+    fn white(&mut self) -> f32 {
+        (self.unit() * 2.0 - 1.0) * 3.0_f32.sqrt()
+    }
+
+    fn sample(&mut self, index: usize) -> f32 {
+        let t = index as f32 / SAMPLE_RATE as f32;
+        // Progress through the whole sound, 0..1
+        let p = index as f32 / self.len.max(1) as f32;
+        let synth = self.synth;
+        match *synth {
+            Synth::Drone { root, seconds } => {
+                let tone = 0.60 * sine(root, t, 0.0)
+                    + 0.25 * sine(root * 2.0, t, 0.3)
+                    + 0.10 * sine(root * 3.0, t, 1.1)
+                    + 0.05 * sine(root * 4.0, t, 0.7);
+                let noise = self.white();
+                let hiss = self.band.process(noise) * 0.04;
+                let tremolo = 0.85 + 0.15 * sine(0.3, t, 0.0);
+                let fade = (t.min(seconds - t) / DRONE_FADE).clamp(0.0, 1.0);
+                (tone + hiss) * tremolo * fade
+            }
+            Synth::Thud { thump, .. } => {
+                let noise = self.white();
+                let grit = self.band.process(noise) * decay(p, 14.0);
+                let body = sine(thump, t, 0.0) * decay(p, 22.0) * 0.8;
+                grit + body
+            }
+            Synth::Bell { tone, .. } => {
+                let noise = self.white();
+                let tick = self.band.process(noise) * decay(p, 40.0) * 0.6;
+                let ring = 0.50 * sine(tone, t, 0.0)
+                    + 0.30 * sine(tone * 2.76, t, 0.0)
+                    + 0.15 * sine(tone * 5.4, t, 0.0);
+                tick + ring * decay(p, 9.0) * 0.5
+            }
+            Synth::Knock { tone, .. } => {
+                let knock = sine(tone, t, 0.0) + 0.5 * sine(tone * 2.3, t, 0.0);
+                let noise = self.white();
+                let grain = self.band.process(noise) * 0.4;
+                knock * decay(p, 16.0) + grain * decay(p, 30.0)
+            }
+            Synth::Sweep { from, to, .. } => {
+                let frequency = from + (to - from) * p;
+                self.phase = (self.phase + TAU * frequency / SAMPLE_RATE as f32) % TAU;
+                self.phase.sin() * decay(p, 8.0)
+            }
+            Synth::Ignition { tone } => {
+                if t < IGNITION_CRACKLE {
+                    let noise = self.white();
+                    let pop = if self.unit() < IGNITION_POPS { 0.5 } else { 0.0 };
+                    (self.band.process(noise) + pop) * decay(t / IGNITION_CRACKLE, 6.0)
+                } else {
+                    let t = t - IGNITION_CRACKLE;
+                    sine(tone, t, 0.0) * 0.6 * (t / IGNITION_TONE)
+                }
+            }
+            Synth::Fanfare { ref notes, note_seconds } => {
+                let (step, t, x) = step_at(index, note_seconds);
+                let Some(&frequency) = notes.get(step) else { return 0.0 };
+                let tone = 0.7 * sine(frequency, t, 0.0)
+                    + 0.2 * sine(frequency * 2.0, t, 0.0)
+                    + 0.1 * sine(frequency * 3.0, t, 0.0);
+                tone * adsr(x, 0.01, 0.15, 0.5, 0.3)
+            }
+            Synth::Pad { ref chords, chord_seconds } => {
+                let (step, t, x) = step_at(index, chord_seconds);
+                let Some(chord) = chords.get(step) else { return 0.0 };
+                // Each note beats gently against a copy a little sharp of it
+                let tone: f32 = chord
+                    .iter()
+                    .map(|&f| 0.5 * sine(f, t, 0.0) + 0.5 * sine(f * PAD_DETUNE, t, 0.9))
+                    .sum();
+                tone / chord.len().max(1) as f32 * adsr(x, 0.3, 0.0, 1.0, 0.3)
+            }
+            Synth::Pluck { ref notes, note_seconds } => {
+                let (step, t, x) = step_at(index, note_seconds);
+                let Some(&frequency) = notes.get(step) else { return 0.0 };
+                let tone = 0.6 * sine(frequency, t, 0.0)
+                    + 0.3 * sine(frequency * 2.0, t, 0.0) * decay(x, 6.0)
+                    + 0.1 * sine(frequency * 4.0, t, 0.0) * decay(x, 12.0);
+                tone * adsr(x, 0.01, 0.0, 1.0, 0.1) * decay(x, 4.0)
+            }
+            Synth::Bass { ref notes, note_seconds } => {
+                let (step, t, x) = step_at(index, note_seconds);
+                let Some(&frequency) = notes.get(step) else { return 0.0 };
+                let tone = 0.85 * sine(frequency, t, 0.0) + 0.15 * sine(frequency * 2.0, t, 0.0);
+                tone * adsr(x, 0.02, 0.3, 0.6, 0.2)
+            }
+            Synth::Pulse { ref steps, step_seconds } => {
+                let (step, t, x) = step_at(index, step_seconds);
+                let level = steps.get(step).copied().unwrap_or(0.0);
+                let noise = self.white();
+                let tick = self.band.process(noise) * decay(x, 30.0) * 0.3;
+                let thump = sine(PULSE_THUMP, t, 0.0) * decay(x, 12.0);
+                (tick + thump) * level
+            }
+            Synth::Crackle { .. } => {
+                let noise = self.white();
+                let roar = self.band.process(noise) * 0.35;
+                if self.unit() < CRACKLE_POPS {
+                    let strength = 0.3 + 0.7 * self.unit();
+                    self.pop += strength;
+                }
+                let pops = self.pop_band.process(self.pop);
+                self.pop *= (-10.0 / (POP_RING * SAMPLE_RATE as f32)).exp();
+                roar + pops
+            }
+            Synth::Hum { root, seconds } => {
+                let seconds = seconds.max(f32::EPSILON);
+                let root = (root * seconds).round() / seconds;
+                // The partner of the octave sits one cycle per loop above it, to beat against
+                let beat = 2.0 / seconds;
+                let tone = 0.50 * sine(root, t, 0.0)
+                    + 0.30 * sine(root * 2.0, t, 0.0)
+                    + 0.30 * sine(root * 2.0 + beat, t, 0.0)
+                    + 0.12 * sine(root * 3.0, t, 0.4)
+                    + 0.06 * sine(root * 6.0, t, 1.3);
+                let shimmer = 0.8 + 0.2 * sine(1.0 / seconds, t, 0.0);
+                tone * shimmer
+            }
+        }
+    }
+}
+
+/// Which `step_seconds`-long step the sample at `index` falls in, with the
+/// seconds and the share of the step gone by at that sample.
+fn step_at(index: usize, step_seconds: f32) -> (usize, f32, f32) {
+    let step_len = ((step_seconds * SAMPLE_RATE as f32) as usize).max(1);
+    let local = index % step_len;
+    (index / step_len, local as f32 / SAMPLE_RATE as f32, local as f32 / step_len as f32)
+}
+
+fn sine(frequency: f32, t: f32, phase: f32) -> f32 {
+    (TAU * frequency * t + phase).sin()
+}
+
+/// Exponential fall from 1 at `p` = 0 to e^-`rate` at `p` = 1.
+fn decay(p: f32, rate: f32) -> f32 {
+    (-rate * p).exp()
+}
+
+/// Attack–decay–sustain–release level at `x` through a note, each stage given
+/// as a fraction of the note.
+fn adsr(x: f32, attack: f32, decay: f32, sustain: f32, release: f32) -> f32 {
+    if x < attack {
+        x / attack
+    } else if x < attack + decay {
+        1.0 - (1.0 - sustain) * (x - attack) / decay
+    } else if x < 1.0 - release {
+        sustain
+    } else {
+        sustain * (1.0 - x) / release
+    }
+}
+
+// ─── Filter ──────────────────────────────────────────────────────────────────
+
+/// Band-pass between two corner frequencies: two biquads in series. The
+/// default passes nothing.
+#[derive(Clone, Copy, Default)]
+struct BandPass {
+    stages: [Biquad; 2],
+}
+
+#[derive(Clone, Copy, Default)]
+struct Biquad {
+    b0: f32,
+    b2: f32,
+    a1: f32,
+    a2: f32,
+    x1: f32,
+    x2: f32,
+    y1: f32,
+    y2: f32,
+}
+
+impl BandPass {
+    fn new((low, high): (f32, f32)) -> Self {
+        let centre = (low * high).sqrt();
+        let q = centre / (high - low).max(1.0);
+        let w = TAU * centre / SAMPLE_RATE as f32;
+        let alpha = w.sin() / (2.0 * q);
+        let a0 = 1.0 + alpha;
+        let stage = Biquad {
+            b0: alpha / a0,
+            b2: -alpha / a0,
+            a1: -2.0 * w.cos() / a0,
+            a2: (1.0 - alpha) / a0,
+            ..default()
+        };
+        Self { stages: [stage; 2] }
+    }
+
+    fn process(&mut self, mut x: f32) -> f32 {
+        for s in &mut self.stages {
+            let y = s.b0 * x + s.b2 * s.x2 - s.a1 * s.y1 - s.a2 * s.y2;
+            s.x2 = s.x1;
+            s.x1 = x;
+            s.y2 = s.y1;
+            s.y1 = y;
+            x = y;
+        }
+        x
+    }
+}
+
+// ─── Playback ────────────────────────────────────────────────────────────────
+
+/// Corner frequency of a fully muffled sound, in Hz.
+const MUFFLED_CUTOFF: f32 = 500.0;
+
+/// Live low-pass on a playing sound, shared with the audio thread: 0 leaves
+/// the sound as rendered, 1 muffles it down to `MUFFLED_CUTOFF`.
+#[derive(Clone, Debug, Default)]
+pub struct Muffle(Arc<AtomicU32>);
+
+impl Muffle {
+    pub fn set(&self, amount: f32) {
+        self.0.store(amount.clamp(0.0, 1.0).to_bits(), Ordering::Relaxed);
+    }
+
+    fn amount(&self) -> f32 {
+        f32::from_bits(self.0.load(Ordering::Relaxed))
+    }
+}
+
+/// What an `AudioPlayer` plays: a synthesized sound and the room it sounds in.
+#[derive(Asset, TypePath, Clone, Debug)]
+pub struct Sound {
+    pub synth: Synth,
+    /// Reverb of the area it plays in; dry when `None`.
+    pub acoustics: Option<Acoustics>,
+    /// Played on a loop, so the reverb wraps round instead of trailing off.
+    pub looping: bool,
+    /// Low-pass the game can sweep while it plays. A muffled loop repeats itself
+    /// and should play `ONCE`: rodio's repeat replays a buffered first pass,
+    /// which would freeze the filter as it was then.
+    pub muffle: Option<Muffle>,
+}
+
+/// Plays a `Sound` rendered with fresh noise.
+pub struct SoundDecoder {
+    samples: Vec<f32>,
+    index: usize,
+    looping: bool,
+    muffle: Option<Muffle>,
+    /// Muffle amount the coefficient was worked out for, and the filter state.
+    amount: f32,
+    coefficient: f32,
+    low: f32,
+}
+
+impl Iterator for SoundDecoder {
+    type Item = f32;
+
+    fn next(&mut self) -> Option<f32> {
+        let Some(amount) = self.muffle.as_ref().map(Muffle::amount) else {
+            let sample = self.samples.get(self.index).copied();
+            self.index += 1;
+            return sample;
+        };
+        if self.looping && self.index >= self.samples.len() {
+            self.index = 0;
+        }
+        let sample = *self.samples.get(self.index)?;
+        self.index += 1;
+        if amount <= 0.0 {
+            self.low = sample;
+            return Some(sample);
+        }
+        if amount != self.amount {
+            // Sweep the one-pole corner down from Nyquist on a log scale
+            let nyquist = SAMPLE_RATE as f32 * 0.5;
+            let cutoff = nyquist * (MUFFLED_CUTOFF / nyquist).powf(amount);
+            self.amount = amount;
+            self.coefficient = 1.0 - (-TAU * cutoff / SAMPLE_RATE as f32).exp();
+        }
+        self.low += (sample - self.low) * self.coefficient;
+        Some(self.low)
+    }
+}
+
+impl SoundDecoder {
+    fn endless(&self) -> bool {
+        self.looping && self.muffle.is_some()
+    }
+}
+
+impl Source for SoundDecoder {
+    fn current_frame_len(&self) -> Option<usize> {
+        if self.endless() {
+            return None;
+        }
+        Some(self.samples.len().saturating_sub(self.index))
+    }
+
+    fn channels(&self) -> u16 {
+        1
+    }
+
+    fn sample_rate(&self) -> u32 {
+        SAMPLE_RATE
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        if self.endless() {
+            return None;
+        }
+        Some(Duration::from_secs_f32(self.samples.len() as f32 / SAMPLE_RATE as f32))
+    }
+}
+
+impl Decodable for Sound {
+    type DecoderItem = f32;
+    type Decoder = SoundDecoder;
+
+    fn decoder(&self) -> SoundDecoder {
+        let seed = NEXT_SEED.fetch_add(SEED_STEP, Ordering::Relaxed);
+        let dry = self.synth.render(seed);
+        let samples = match &self.acoustics {
+            Some(acoustics) => acoustics.apply(&dry, self.looping),
+            None => dry,
+        };
+        SoundDecoder {
+            samples,
+            index: 0,
+            looping: self.looping,
+            muffle: self.muffle.clone(),
+            amount: 0.0,
+            coefficient: 1.0,
+            low: 0.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// One of every kind of sound, with parameters like the ones in the cue table.
+    fn every_synth() -> Vec<Synth> {
+        vec![
+            Synth::Drone { root: 55.0, seconds: 1.0 },
+            Synth::Thud { seconds: 0.25, low: 200.0, high: 1200.0, thump: 80.0 },
+            Synth::Bell { seconds: 0.8, tone: 1400.0 },
+            Synth::Knock { seconds: 0.2, tone: 300.0 },
+            Synth::Sweep { seconds: 0.3, from: 600.0, to: 900.0 },
+            Synth::Ignition { tone: 220.0 },
+            Synth::Fanfare { notes: vec![440.0, 554.37, 659.25], note_seconds: 0.2 },
+            Synth::Crackle { seconds: 1.0 },
+            Synth::Hum { root: 220.3, seconds: 2.0 },
+            Synth::Pad {
+                chords: vec![vec![220.0, 261.63, 329.63], vec![174.61, 220.0, 261.63]],
+                chord_seconds: 0.5,
+            },
+            Synth::Pluck { notes: vec![440.0, 0.0, 523.25], note_seconds: 0.25 },
+            Synth::Bass { notes: vec![110.0, 0.0, 87.31], note_seconds: 0.25 },
+            Synth::Pulse { steps: vec![1.0, 0.0, 0.5, 0.0], step_seconds: 0.2 },
+        ]
+    }
+
+    #[test]
+    fn renders_the_stated_length_within_peak() {
+        for synth in every_synth() {
+            let samples = synth.render(1);
+            assert_eq!(samples.len(), (synth.seconds() * SAMPLE_RATE as f32) as usize, "{synth:?}");
+            assert!(samples.iter().all(|s| s.is_finite()), "{synth:?} rendered NaN");
+            let peak = samples.iter().fold(0.0_f32, |peak, s| peak.max(s.abs()));
+            assert!(peak <= PEAK + 1e-6, "{synth:?} peaks at {peak}");
+            assert!(peak > 0.0, "{synth:?} is silent");
+        }
+    }
+
+    #[test]
+    fn same_seed_renders_the_same_samples() {
+        for synth in every_synth() {
+            assert_eq!(synth.render(42), synth.render(42), "{synth:?}");
+        }
+    }
+
+    #[test]
+    fn different_seeds_render_different_noise() {
+        let thud = Synth::Thud { seconds: 0.25, low: 200.0, high: 1200.0, thump: 0.0 };
+        assert_ne!(thud.render(1), thud.render(2));
+        let crackle = Synth::Crackle { seconds: 1.0 };
+        assert_ne!(crackle.render(1), crackle.render(2));
+    }
+
+    #[test]
+    fn hum_loops_without_a_seam() {
+        let samples = Synth::Hum { root: 220.3, seconds: 2.0 }.render(1);
+        let largest_step =
+            samples.windows(2).map(|pair| (pair[1] - pair[0]).abs()).fold(0.0_f32, f32::max);
+        let seam = (samples[0] - samples[samples.len() - 1]).abs();
+        assert!(seam <= largest_step * 1.05, "seam {seam} against steps up to {largest_step}");
+    }
+}
@@ -1,7 +1,16 @@
 use bevy::{gltf::GltfAssetLabel, prelude::*};
+use serde::{Deserialize, Serialize};
 
 use crate::{
-    game::{area::{AreaBounds, GameEntity}, camera::CameraRig},
+    game::{
+        area::{AreaBounds, GameEntity, SelectedArea},
+        camera::CameraRig,
+        collision::{Collider, ColliderShape, GroundProbe, slide},
+        messages::{Footfall, Jumped, Landed},
+        physics::PhysicsTranslation,
+    },
+    input::{Action, Actions},
+    save::ActiveSave,
     state::State,
 };
 
@@ -12,21 +21,58 @@ pub struct Cat {
     mode: CatMode,
 }
 
-#[derive(Default)]
-enum CatMode {
+impl Cat {
+    pub fn mode(&self) -> CatMode {
+        self.mode
+    }
+}
+
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum CatMode {
     #[default]
     Normal,
     Black,
     White,
 }
 
+impl CatMode {
+    fn next(self) -> Self {
+        match self {
+            CatMode::Normal => CatMode::Black,
+            CatMode::Black  => CatMode::White,
+            CatMode::White  => CatMode::Normal,
+        }
+    }
+
+    fn coat_color(self) -> Color {
+        match self {
+            CatMode::Normal => Color::srgb(0.478, 0.392, 0.082),
+            CatMode::Black  => Color::srgb(0.05, 0.04, 0.04),
+            CatMode::White  => Color::srgb(0.92, 0.92, 0.90),
+        }
+    }
+}
+
+fn tint_coat(mode: CatMode, mesh_mats: &[Handle<StandardMaterial>], materials: &mut Assets<StandardMaterial>) {
+    let color = mode.coat_color();
+    for handle in mesh_mats {
+        if let Some(mat) = materials.get_mut(handle) {
+            mat.base_color = color;
+        }
+    }
+}
+
 // ── Movement component ────────────────────────────────────────────────────────
 
-/// Current movement speed — written by move_cat.
+/// Character controller state — written by move_cat. Speeds are in metres per second.
 #[derive(Component, Default)]
 pub struct CatLocomotion {
-    pub velocity: f32,
+    /// Horizontal (XZ) velocity.
+    pub velocity: Vec3,
     pub y_velocity: f32,
+    pub grounded: bool,
+    /// Braking hard before pivoting to face a sharply reversed direction.
+    pub skidding: bool,
 }
 
 // ── Animation components ──────────────────────────────────────────────────────
@@ -46,6 +92,15 @@ enum CatAnimState {
     Run,
 }
 
+/// Gait clips and the cycle position last seen, for timing footfalls.
+#[derive(Component)]
+struct CatStride {
+    walk: Handle<AnimationClip>,
+    run:  Handle<AnimationClip>,
+    /// Gait node and its cycle fraction on the previous frame.
+    last: Option<(AnimationNodeIndex, f32)>,
+}
+
 /// Holds the entity that owns the `AnimationPlayer` (a GLTF child entity).
 #[derive(Component)]
 pub struct CatAnimPlayer(pub Entity);
@@ -62,18 +117,32 @@ struct CatMeshMaterials(Vec<Handle<StandardMaterial>>);
 
 pub fn setup_cat(
     existing: Query<(), With<Cat>>,
+    selected: Res<SelectedArea>,
+    save: Res<ActiveSave>,
     mut commands: Commands,
     asset_server: Res<AssetServer>,
 ) {
     if !existing.is_empty() {
         return;
     }
+    // Resume where the cat was last standing in this area, if saved
+    let saved = save.area(&selected.0).and_then(|a| a.cat);
+    let mut transform = Transform::from_xyz(-1.0, 0.0, 0.0);
+    if let Some(cat) = &saved {
+        cat.transform.apply(&mut transform);
+    }
     commands.spawn((
         GameEntity,
         Name::new("Cat"),
-        Transform::from_xyz(-1.0, 0.0, 0.0),
-        Cat::default(),
+        transform,
+        Cat { mode: saved.map(|c| c.mode).unwrap_or_default() },
         CatLocomotion::default(),
+        PhysicsTranslation::default(),
+        // Long body from nose to tail; the translation sits at the paws
+        Collider {
+            shape: ColliderShape::Capsule { half_length: 0.22, radius: 0.18, half_height: 0.25 },
+            elevation: 0.25,
+        },
         SceneRoot(asset_server.load(GltfAssetLabel::Scene(0).from_asset("models/cat.glb"))),
     ));
 }
@@ -116,14 +185,15 @@ fn find_animation_player(
 
 fn init_cat_animation(
     mut commands: Commands,
-    cats: Query<Entity, (With<Cat>, Without<CatAnimationInitialized>)>,
+    cats: Query<(Entity, &Cat), Without<CatAnimationInitialized>>,
     children: Query<&Children>,
     animation_players: Query<Entity, With<AnimationPlayer>>,
     mat_handles: Query<&MeshMaterial3d<StandardMaterial>>,
     asset_server: Res<AssetServer>,
     mut graphs: ResMut<Assets<AnimationGraph>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
 ) {
-    for cat_entity in &cats {
+    for (cat_entity, cat) in &cats {
         let Some(player_entity) =
             find_animation_player(cat_entity, &children, &animation_players)
         else {
@@ -140,13 +210,17 @@ fn init_cat_animation(
 
         let mut graph = AnimationGraph::new();
         let idle_node = graph.add_clip(idle_clip, 1.0, graph.root);
-        let walk_node = graph.add_clip(walk_clip, 1.0, graph.root);
-        let run_node  = graph.add_clip(run_clip,  1.0, graph.root);
+        let walk_node = graph.add_clip(walk_clip.clone(), 1.0, graph.root);
+        let run_node  = graph.add_clip(run_clip.clone(),  1.0, graph.root);
 
         let graph_handle = graphs.add(graph);
 
         let mut mesh_materials = Vec::new();
         collect_std_materials(cat_entity, &children, &mat_handles, &mut mesh_materials);
+        // A restored coat must be applied once the GLTF materials exist
+        if cat.mode != CatMode::Normal {
+            tint_coat(cat.mode, &mesh_materials, &mut materials);
+        }
 
         commands
             .entity(player_entity)
@@ -155,6 +229,7 @@ fn init_cat_animation(
             CatAnimationNodes { idle: idle_node, walk: walk_node, run: run_node },
             CatAnimState::Idle,
             CatAnimPlayer(player_entity),
+            CatStride { walk: walk_clip, run: run_clip, last: None },
             CatAnimationInitialized,
             CatMeshMaterials(mesh_materials),
         ));
@@ -163,6 +238,9 @@ fn init_cat_animation(
 
 // ── Animation update ──────────────────────────────────────────────────────────
 
+/// Below this speed the cat counts as standing still.
+const STILL_SPEED: f32 = 0.3;
+
 fn animate_cat(
     mut players: Query<&mut AnimationPlayer>,
     mut cats: Query<
@@ -175,99 +253,258 @@ fn animate_cat(
             continue;
         };
 
-        let target = if locomotion.velocity >= 0.15 {
+        let speed = locomotion.speed();
+        let target = if speed >= (WALK_SPEED + RUN_SPEED) * 0.5 {
             CatAnimState::Run
-        } else if locomotion.velocity > 0.0 {
+        } else if speed > STILL_SPEED {
             CatAnimState::Walk
         } else {
             CatAnimState::Idle
         };
 
+        let node = match target {
+            CatAnimState::Idle => nodes.idle,
+            CatAnimState::Walk => nodes.walk,
+            CatAnimState::Run  => nodes.run,
+        };
         if *anim_state != target {
             *anim_state = target;
-            let node = match target {
-                CatAnimState::Idle => nodes.idle,
-                CatAnimState::Walk => nodes.walk,
-                CatAnimState::Run  => nodes.run,
-            };
             player.play(node).repeat();
         }
+
+        // Scale the gait so the paws keep up with the real speed
+        let playback = match target {
+            CatAnimState::Idle => 1.0,
+            CatAnimState::Walk => (speed / WALK_SPEED).clamp(0.5, 1.5),
+            CatAnimState::Run  => (speed / RUN_SPEED).clamp(0.75, 1.5),
+        };
+        if let Some(active) = player.animation_mut(node) {
+            active.set_speed(playback);
+        }
+    }
+}
+
+/// Where paws strike the ground, as fractions of the walk and run cycles: the
+/// walk's diagonal pairs and the run's front and back pairs land half a cycle apart.
+const FOOTFALLS: [f32; 2] = [0.0, 0.5];
+
+/// True if `mark` was passed going from cycle fraction `from` to `to`, allowing
+/// for the cycle wrapping around in between.
+fn crossed(from: f32, to: f32, mark: f32) -> bool {
+    if to >= from {
+        from < mark && mark <= to
+    } else {
+        mark > from || mark <= to
+    }
+}
+
+/// Sends a `Footfall` whenever the walk or run cycle reaches a paw strike.
+fn time_footfalls(
+    clips: Res<Assets<AnimationClip>>,
+    players: Query<&AnimationPlayer>,
+    ground: GroundProbe,
+    mut footfalls: MessageWriter<Footfall>,
+    mut cats: Query<(
+        Entity,
+        &Transform,
+        &CatLocomotion,
+        &CatAnimState,
+        &CatAnimationNodes,
+        &CatAnimPlayer,
+        &mut CatStride,
+    )>,
+) {
+    for (cat, transform, locomotion, anim_state, nodes, cat_player, mut stride) in &mut cats {
+        let (node, clip) = match anim_state {
+            CatAnimState::Walk => (nodes.walk, &stride.walk),
+            CatAnimState::Run  => (nodes.run, &stride.run),
+            CatAnimState::Idle => {
+                stride.last = None;
+                continue;
+            }
+        };
+        let Ok(player) = players.get(cat_player.0) else { continue };
+        let (Some(active), Some(clip)) = (player.animation(node), clips.get(clip)) else {
+            continue;
+        };
+        if clip.duration() <= 0.0 {
+            continue;
+        }
+        let phase = (active.seek_time() / clip.duration()).rem_euclid(1.0);
+        let last = stride.last.replace((node, phase));
+        // Paws in the air make no sound, and a change of gait starts a new cycle
+        let Some((last_node, last_phase)) = last else { continue };
+        if !locomotion.grounded || last_node != node {
+            continue;
+        }
+        if FOOTFALLS.iter().any(|&mark| crossed(last_phase, phase, mark)) {
+            let surface = ground.surface_below(transform.translation);
+            footfalls.write(Footfall { cat, surface });
+        }
     }
 }
 
 // ── Movement ──────────────────────────────────────────────────────────────────
 
-const GRAVITY: f32 = -22.0;
+/// Vertical acceleration of everything that falls or rolls, in m/s².
+pub const GRAVITY: f32 = -22.0;
 const JUMP_FORCE: f32 = 9.0;
+/// Top ground speeds.
+const WALK_SPEED: f32 = 6.0;
+const RUN_SPEED: f32 = 12.0;
+/// Gaining speed towards the input target, in m/s².
+const ACCELERATION: f32 = 40.0;
+/// Losing speed when the input eases off or is released, in m/s².
+const DECELERATION: f32 = 30.0;
+/// Braking while skidding, in m/s².
+const SKID_DECELERATION: f32 = 60.0;
+/// Maximum facing change, in radians per second.
+const TURN_RATE: f32 = 10.0;
+/// Largest drop the paws stay planted through while walking, e.g. down a slope.
+const SNAP_DISTANCE: f32 = 0.25;
+/// Fraction of acceleration and turn rate available in the air.
+const AIR_CONTROL: f32 = 0.3;
+/// A skid starts when the input points this far from the heading (cos ~135°)...
+const SKID_DOT: f32 = -0.7;
+/// ...while moving at least this fast,
+const SKID_MIN_SPEED: f32 = 4.0;
+/// and ends with a pivot once braked below this speed.
+const PIVOT_SPEED: f32 = 1.0;
+
+impl CatLocomotion {
+    /// Current ground speed.
+    pub fn speed(&self) -> f32 {
+        self.velocity.length()
+    }
+
+    pub fn is_moving(&self) -> bool {
+        self.speed() > STILL_SPEED
+    }
+}
+
+/// Moves `current` towards `target` by at most `max_delta`.
+fn approach(current: f32, target: f32, max_delta: f32) -> f32 {
+    current + (target - current).clamp(-max_delta, max_delta)
+}
+
+/// Turns the XZ `heading` towards `wanted` by at most `max_angle` radians.
+fn turn_towards(heading: Vec3, wanted: Vec3, max_angle: f32) -> Vec3 {
+    let from = heading.xz();
+    let angle = from.angle_to(wanted.xz()).clamp(-max_angle, max_angle);
+    let turned = Vec2::from_angle(angle).rotate(from);
+    Vec3::new(turned.x, 0.0, turned.y)
+}
 
-fn move_cat(
+/// Input sampled every frame and consumed by the fixed-step movement,
+/// so a jump pressed between two fixed steps is never lost.
+#[derive(Resource, Default)]
+struct CatIntent {
+    /// Camera-relative XZ direction, scaled by input strength (length 0..=1).
+    direction: Vec3,
+    run: bool,
+    jump: bool,
+}
+
+fn sample_cat_intent(
+    actions: Actions,
+    rigs: Query<&Transform, With<CameraRig>>,
+    mut intent: ResMut<CatIntent>,
+) {
+    let Ok(rig) = rigs.single() else { return };
+    let input = actions.move_axis();
+    let forward = rig.forward().with_y(0.).normalize_or_zero();
+    let right   = rig.right().with_y(0.).normalize_or_zero();
+    intent.direction = input.y * forward + input.x * right;
+    intent.run = actions.pressed(Action::Run);
+    // Held until the next fixed step picks it up
+    intent.jump |= actions.just_pressed(Action::Jump);
+}
+
+pub fn move_cat(
     time: Res<Time>,
-    keyboard_input: Res<ButtonInput<KeyCode>>,
     bounds: Res<AreaBounds>,
-    mut set: ParamSet<(
-        Query<(&mut Transform, &mut CatLocomotion), With<Cat>>,
-        Query<&Transform, With<CameraRig>>,
-    )>,
+    ground: GroundProbe,
+    mut intent: ResMut<CatIntent>,
+    mut jumped: MessageWriter<Jumped>,
+    mut landed: MessageWriter<Landed>,
+    mut query: Query<(Entity, &mut PhysicsTranslation, &mut Transform, &mut CatLocomotion), With<Cat>>,
 ) {
-    let rig = set.p1().single().expect("Camera Rig not spawned").clone();
-    let mut query = set.p0();
     let dt = time.delta_secs();
+    let jump = std::mem::take(&mut intent.jump);
 
-    let pressed = keyboard_input.get_pressed();
-    let mut input_direction = Vec3::ZERO;
-    for key in pressed {
-        match key {
-            KeyCode::KeyW => input_direction.z += 1.,
-            KeyCode::KeyS => input_direction.z -= 1.,
-            KeyCode::KeyD => input_direction.x += 1.,
-            KeyCode::KeyA => input_direction.x -= 1.,
-            _ => (),
-        }
-    }
-
-    for (mut transform, mut locomotion) in query.iter_mut() {
-        // ── Jump & gravity ─────────────────────────────────────────────────
-        let grounded = transform.translation.y <= 0.001;
-        if keyboard_input.just_pressed(KeyCode::Space) && grounded {
+    for (cat, mut position, mut transform, mut locomotion) in query.iter_mut() {
+        // ── Jump ───────────────────────────────────────────────────────────
+        if jump && locomotion.grounded {
             locomotion.y_velocity = JUMP_FORCE;
+            locomotion.grounded = false;
+            locomotion.skidding = false;
+            jumped.write(Jumped { cat });
         }
-        locomotion.y_velocity += GRAVITY * dt;
-        transform.translation.y += locomotion.y_velocity * dt;
-        if transform.translation.y <= 0.0 {
-            transform.translation.y = 0.0;
-            locomotion.y_velocity = 0.0;
-        }
+        let was_grounded = locomotion.grounded;
 
         // ── Horizontal movement ────────────────────────────────────────────
-        if input_direction != Vec3::ZERO {
-            let forward = rig.forward().with_y(0.).normalize();
-            let right   = rig.right().normalize();
-            let move_direction =
-                (input_direction.z * forward + input_direction.x * right).normalize();
-
-            let speed = if keyboard_input.pressed(KeyCode::ShiftLeft) {
-                0.2 // run
+        let control = if locomotion.grounded { 1.0 } else { AIR_CONTROL };
+        let mut heading = transform.forward().with_y(0.0).normalize_or(Vec3::NEG_Z);
+        let mut speed = locomotion.speed();
+
+        if intent.direction != Vec3::ZERO {
+            let wanted = intent.direction.normalize();
+            let base_speed = if intent.run { RUN_SPEED } else { WALK_SPEED };
+            // Partial stick deflection lowers the target speed
+            let target_speed = base_speed * intent.direction.length();
+
+            let reversing = heading.dot(wanted) < SKID_DOT && speed > SKID_MIN_SPEED;
+            if locomotion.grounded && (locomotion.skidding || reversing) {
+                // Brake along the old heading, then pivot in place
+                locomotion.skidding = true;
+                speed = approach(speed, 0.0, SKID_DECELERATION * dt);
+                if speed <= PIVOT_SPEED {
+                    locomotion.skidding = false;
+                    heading = wanted;
+                }
             } else {
-                0.1 // walk
-            };
-
-            transform.translation += move_direction * speed;
-
-            // Clamp XZ to area play radius
-            let xz = Vec2::new(transform.translation.x, transform.translation.z);
-            if xz.length() > bounds.play_radius {
-                let clamped = xz.normalize() * bounds.play_radius;
-                transform.translation.x = clamped.x;
-                transform.translation.z = clamped.y;
+                heading = turn_towards(heading, wanted, TURN_RATE * control * dt);
+                let rate = if target_speed > speed { ACCELERATION } else { DECELERATION };
+                speed = approach(speed, target_speed, rate * control * dt);
             }
+        } else {
+            locomotion.skidding = false;
+            // Momentum carries through the air; only the ground brakes
+            if locomotion.grounded {
+                speed = approach(speed, 0.0, DECELERATION * dt);
+            }
+        }
 
-            // Face the movement direction
-            let target = transform.translation + move_direction;
-            transform.look_at(target, Vec3::Y);
-
-            locomotion.velocity = speed;
+        locomotion.velocity = heading * speed;
+        let next = position.0 + locomotion.velocity * dt;
+        if ground.is_ledge(next) {
+            // Terrain rises too steeply to walk up — stop at the foot of the ledge
+            locomotion.velocity = Vec3::ZERO;
         } else {
-            locomotion.velocity = 0.0;
+            position.0 = next;
+        }
+        if let Some(wall_normal) = bounds.play.clamp_xz(&mut position.0) {
+            slide(&mut locomotion.velocity, wall_normal);
+        }
+
+        transform.look_to(heading, Vec3::Y);
+
+        // ── Gravity & landing ──────────────────────────────────────────────
+        locomotion.y_velocity += GRAVITY * dt;
+        position.0.y += locomotion.y_velocity * dt;
+        // Land on whatever is under the paws; walking off an edge starts a fall,
+        // but small drops like a downhill slope keep the paws on the ground
+        let ground_height = ground.ground_below(position.0);
+        let drop = position.0.y - ground_height;
+        let on_ground = drop <= 0.0 || (was_grounded && drop <= SNAP_DISTANCE);
+        locomotion.grounded = on_ground && locomotion.y_velocity <= 0.0;
+        if locomotion.grounded {
+            if !was_grounded {
+                let surface = ground.surface_below(position.0);
+                landed.write(Landed { cat, impact: -locomotion.y_velocity, surface });
+            }
+            position.0.y = ground_height;
+            locomotion.y_velocity = 0.0;
         }
     }
 }
@@ -275,39 +512,26 @@ fn move_cat(
 // ── Color toggle ──────────────────────────────────────────────────────────────
 
 fn change_mode(
-    keyboard_input: Res<ButtonInput<KeyCode>>,
+    actions: Actions,
     mut cats: Query<(&mut Cat, &CatMeshMaterials)>,
     mut materials: ResMut<Assets<StandardMaterial>>,
 ) {
-    if !keyboard_input.just_pressed(KeyCode::Tab) {
+    if !actions.just_pressed(Action::CycleCoat) {
         return;
     }
     for (mut cat, mesh_mats) in &mut cats {
-        cat.mode = match cat.mode {
-            CatMode::Normal => CatMode::Black,
-            CatMode::Black  => CatMode::White,
-            CatMode::White  => CatMode::Normal,
-        };
-        let color = match cat.mode {
-            CatMode::Normal => Color::srgb(0.478, 0.392, 0.082),
-            CatMode::Black  => Color::srgb(0.05, 0.04, 0.04),
-            CatMode::White  => Color::srgb(0.92, 0.92, 0.90),
-        };
-        for handle in &mesh_mats.0 {
-            if let Some(mat) = materials.get_mut(handle) {
-                mat.base_color = color;
-            }
-        }
+        cat.mode = cat.mode.next();
+        tint_coat(cat.mode, &mesh_mats.0, &mut materials);
     }
 }
 
 // ── Pause ─────────────────────────────────────────────────────────────────────
 
 fn exit_play(
-    keyboard_input: Res<ButtonInput<KeyCode>>,
+    actions: Actions,
     mut next_state: ResMut<NextState<State>>,
 ) {
-    if keyboard_input.just_pressed(KeyCode::Escape) {
+    if actions.just_pressed(Action::Pause) {
         next_state.set(State::Paused);
     }
 }
@@ -317,13 +541,21 @@ fn exit_play(
 pub struct CatPlugin;
 impl Plugin for CatPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(OnEnter(State::Playing), setup_cat)
+        app.init_resource::<CatIntent>()
+            .add_systems(OnEnter(State::Playing), setup_cat)
+            .add_systems(
+                RunFixedMainLoop,
+                sample_cat_intent
+                    .in_set(RunFixedMainLoopSystems::BeforeFixedMainLoop)
+                    .run_if(in_state(State::Playing)),
+            )
+            .add_systems(FixedUpdate, move_cat.run_if(in_state(State::Playing)))
             .add_systems(
                 Update,
                 (
                     init_cat_animation,
-                    move_cat,
-                    animate_cat.after(move_cat),
+                    animate_cat,
+                    time_footfalls.after(animate_cat),
                     change_mode,
                     exit_play,
                 )
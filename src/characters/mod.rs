@@ -1,6 +1,10 @@
 mod cat;
 
-pub use cat::{Cat, CatLocomotion, CatPlugin};
+pub use cat::{Cat, CatLocomotion, CatMode, CatPlugin};
 
 // Exported for the setup_camera ordering constraint in main.rs
 pub use cat::setup_cat;
+// Exported so fixed-step collision resolution can run after it
+pub use cat::move_cat;
+// Exported so pushable bodies roll downhill under the same gravity
+pub use cat::GRAVITY;
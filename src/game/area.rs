@@ -1,81 +1,305 @@
-use bevy::prelude::{
-    Assets, Circle, Color, Commands, Component, Mesh, Mesh3d, MeshMaterial3d, Query, Quat, Res,
-    ResMut, Resource, StandardMaterial, States, Transform, With,
+use std::collections::HashMap;
+
+use bevy::{
+    pbr::{DistanceFog, FogFalloff},
+    prelude::*,
+    render::render_resource::Face,
 };
-use strum::{EnumCount, EnumIter, EnumString, IntoStaticStr};
 
-use crate::render::{RockExtension, RockMaterial};
+use crate::{
+    game::{
+        area_def::{AreaDef, AreaRegistry, ColliderDef, MaterialDef, rgb},
+        boundary::Boundary,
+        camera::setup_camera,
+        collision::{Collider, ColliderShape},
+        interactables::{Lightable, Spent, Tippable, insert_interactable, light_up},
+        portal::Portal,
+        terrain::{Heightfield, apply_area_terrain},
+    },
+    render::{RockExtension, RockMaterial},
+    save::ActiveSave,
+    state::State,
+};
 
 /// Marker for all entities that belong to the game world.
 /// Despawned when returning to the main menu so pausing preserves them.
 #[derive(Component)]
 pub struct GameEntity;
 
-#[derive(
-    Clone, Copy, PartialEq, Eq, Hash, Debug, Default, States, EnumIter, IntoStaticStr, EnumCount, EnumString,
-)]
-pub enum Area {
-    #[default]
-    Cave,
-    CrystalCavern,
-}
+/// Marker for everything spawned from the selected `AreaDef`.
+#[derive(Component)]
+pub struct AreaObject;
+
+/// Marker for the area's floor mesh.
+#[derive(Component)]
+pub struct Floor;
+
+/// Index of the object in `AreaDef::objects` — a stable id for save games.
+#[derive(Component, Clone, Copy)]
+pub struct AreaObjectId(pub usize);
 
-/// Tracks which area the player selected from the area menu.
+/// Id (file stem in `assets/areas`) of the area the player selected from the area menu.
 #[derive(Resource, Default)]
-pub struct SelectedArea(pub Area);
+pub struct SelectedArea(pub String);
 
-/// Per-area boundary radii — set by `apply_area_bounds` on `OnEnter(State::Playing)`.
+/// Per-area boundaries — set by `apply_area_bounds` on `OnEnter(State::Playing)`.
 #[derive(Resource)]
 pub struct AreaBounds {
-    /// XZ radius for cat movement and object clamping.
-    pub play_radius: f32,
-    /// XZ radius for the camera orbit clamp.
-    pub camera_radius: f32,
+    /// Outline for cat movement and object clamping.
+    pub play: Boundary,
+    /// Outline for the camera orbit clamp.
+    pub camera: Boundary,
 }
 
 impl Default for AreaBounds {
     fn default() -> Self {
-        Self { play_radius: 3.5, camera_radius: 7.8 }
+        Self {
+            play: Boundary::Circle { center: Vec2::ZERO, radius: 3.5 },
+            camera: Boundary::Circle { center: Vec2::ZERO, radius: 7.8 },
+        }
     }
 }
 
+/// Either kind of material an `AreaDef` palette entry can produce.
+enum AreaMaterial {
+    Rock(Handle<RockMaterial>),
+    Standard(Handle<StandardMaterial>),
+}
+
+fn build_material(
+    def: &MaterialDef,
+    rock_materials: &mut Assets<RockMaterial>,
+    std_materials: &mut Assets<StandardMaterial>,
+) -> AreaMaterial {
+    match *def {
+        MaterialDef::Rock { color, roughness, noise_scale, double_sided, cull_front } => {
+            AreaMaterial::Rock(rock_materials.add(RockMaterial {
+                base: StandardMaterial {
+                    base_color: rgb(color),
+                    perceptual_roughness: roughness,
+                    double_sided,
+                    cull_mode: cull_front.then_some(Face::Front),
+                    ..default()
+                },
+                extension: RockExtension::new(noise_scale),
+            }))
+        }
+        MaterialDef::Standard { color, alpha, emissive, roughness, reflectance } => {
+            AreaMaterial::Standard(std_materials.add(StandardMaterial {
+                base_color: rgb(color).with_alpha(alpha),
+                emissive: LinearRgba::rgb(emissive.0, emissive.1, emissive.2),
+                perceptual_roughness: roughness,
+                reflectance,
+                alpha_mode: if alpha < 1.0 { AlphaMode::Blend } else { AlphaMode::Opaque },
+                ..default()
+            }))
+        }
+    }
+}
+
+fn load_area_registry(mut commands: Commands, mut selected: ResMut<SelectedArea>) {
+    let registry = AreaRegistry::load();
+    if selected.0.is_empty() {
+        if let Some(id) = registry.first_id() {
+            selected.0 = id.to_string();
+        }
+    }
+    commands.insert_resource(registry);
+}
+
+/// Looks up the definition for the currently selected area.
+pub fn selected_def<'a>(registry: &'a AreaRegistry, selected: &SelectedArea) -> Option<&'a AreaDef> {
+    let def = registry.get(&selected.0);
+    if def.is_none() {
+        warn!("No area definition for {:?}", selected.0);
+    }
+    def
+}
+
 /// Writes the correct `AreaBounds` values for the selected area.
 /// Must run before any system that reads `AreaBounds`.
 pub fn apply_area_bounds(
     selected: Res<SelectedArea>,
+    registry: Res<AreaRegistry>,
     mut bounds: ResMut<AreaBounds>,
 ) {
-    match selected.0 {
-        Area::Cave          => { bounds.play_radius = 3.5;  bounds.camera_radius = 7.8;  }
-        Area::CrystalCavern => { bounds.play_radius = 12.5; bounds.camera_radius = 15.5; }
-    }
+    let Some(def) = selected_def(&registry, &selected) else { return };
+    bounds.play = def.bounds.play.to_boundary();
+    bounds.camera = def.bounds.camera.to_boundary();
 }
 
+/// Spawns the floor, every object and every light described by the selected `AreaDef`,
+/// restoring lit, tipped and moved objects from the active save.
 pub fn setup_area(
-    existing: Query<(), With<GameEntity>>,
+    existing: Query<(), With<AreaObject>>,
     selected: Res<SelectedArea>,
+    registry: Res<AreaRegistry>,
+    save: Res<ActiveSave>,
+    heightfield: Res<Heightfield>,
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut rock_materials: ResMut<Assets<RockMaterial>>,
+    mut std_materials: ResMut<Assets<StandardMaterial>>,
 ) {
     if !existing.is_empty() {
         return;
     }
-    let (base_color, noise_scale, floor_radius) = match selected.0 {
-        Area::Cave          => (Color::srgb(0.22, 0.18, 0.15), 2.0, 4.0_f32),
-        Area::CrystalCavern => (Color::srgb(0.08, 0.06, 0.14), 1.5, 14.0_f32),
+    let Some(def) = selected_def(&registry, &selected) else { return };
+    let saved = save.area(&selected.0).cloned().unwrap_or_default();
+
+    let (floor_mesh, floor_transform) = match def.floor.terrain {
+        Some(_) => (heightfield.mesh(def.floor.radius), Transform::default()),
+        None => (
+            Circle::new(def.floor.radius).into(),
+            Transform::from_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2)),
+        ),
     };
     commands.spawn((
         GameEntity,
-        Mesh3d(meshes.add(Circle::new(floor_radius))),
+        AreaObject,
+        Name::new("Floor"),
+        Floor,
+        def.floor.surface,
+        Mesh3d(meshes.add(floor_mesh)),
         MeshMaterial3d(rock_materials.add(RockMaterial {
             base: StandardMaterial {
-                base_color,
-                perceptual_roughness: 0.95,
-                ..Default::default()
+                base_color: rgb(def.floor.color),
+                perceptual_roughness: def.floor.roughness,
+                ..default()
             },
-            extension: RockExtension::new(noise_scale),
+            extension: RockExtension::new(def.floor.noise_scale),
         })),
-        Transform::from_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2)),
+        floor_transform,
     ));
+
+    let palette: HashMap<&str, AreaMaterial> = def
+        .materials
+        .iter()
+        .map(|(name, m)| (name.as_str(), build_material(m, &mut rock_materials, &mut std_materials)))
+        .collect();
+
+    for (index, object) in def.objects.iter().enumerate() {
+        let Some(material) = palette.get(object.material.as_str()) else {
+            warn!("{}: unknown material {:?}", object.name, object.material);
+            continue;
+        };
+        if saved.has_secret(index) {
+            continue; // already found
+        }
+        let lit = saved.is_lit(index);
+        let mut transform = object.transform();
+        // Heights in the area file are relative to the floor beneath each object
+        transform.translation.y += heightfield.height_at(transform.translation.xz());
+        if let Some(moved) = saved.transform(index) {
+            moved.apply(&mut transform);
+        }
+        let mut entity = commands.spawn((
+            GameEntity,
+            AreaObject,
+            AreaObjectId(index),
+            Name::new(object.name.clone()),
+            Mesh3d(meshes.add(object.mesh.to_mesh())),
+            transform,
+        ));
+        match material {
+            AreaMaterial::Rock(handle) => {
+                entity.insert(MeshMaterial3d(handle.clone()));
+            }
+            AreaMaterial::Standard(handle) => {
+                // Interactables get their own copy so highlight and lit glow stay per-object
+                let handle = match &object.interact {
+                    Some(interact) => std_materials
+                        .get(handle)
+                        .cloned()
+                        .map(|mut m| {
+                            if lit {
+                                light_up(&mut m, interact.kind);
+                            }
+                            std_materials.add(m)
+                        })
+                        .unwrap_or_else(|| handle.clone()),
+                    None => handle.clone(),
+                };
+                entity.insert(MeshMaterial3d(handle));
+            }
+        }
+        if let Some(collider) = object.collider {
+            let half_height = object.mesh.half_height();
+            let shape = match collider {
+                ColliderDef::Circle(radius) => ColliderShape::Circle { radius, half_height },
+                ColliderDef::Box(x, z) => ColliderShape::Box { half_extents: Vec3::new(x, half_height, z) },
+                ColliderDef::Capsule(half_length, radius) => {
+                    ColliderShape::Capsule { half_length, radius, half_height }
+                }
+            };
+            entity.insert(Collider { shape, elevation: 0.0 });
+        }
+        if let Some(interact) = &object.interact {
+            insert_interactable(&mut entity, interact);
+            if lit {
+                entity.insert((Lightable { lit: true, kind: interact.kind }, Spent));
+            }
+            if saved.is_tipped(index) {
+                entity.insert(Tippable { tipped: true });
+            }
+        }
+        if let Some(portal) = &object.portal {
+            entity.insert(Portal::new(portal));
+        }
+        if let Some(surface) = object.surface {
+            entity.insert(surface);
+        }
+        if let Some(light) = &object.light {
+            entity.with_child((light.to_light(), Transform::default()));
+        }
+    }
+
+    for light in &def.directional_lights {
+        commands.spawn((
+            GameEntity,
+            AreaObject,
+            DirectionalLight {
+                color: rgb(light.color),
+                illuminance: light.illuminance,
+                shadows_enabled: false,
+                ..default()
+            },
+            light.transform(),
+        ));
+    }
+}
+
+/// Applies the selected area's fog to the gameplay camera.
+fn apply_area_fog(
+    selected: Res<SelectedArea>,
+    registry: Res<AreaRegistry>,
+    mut cameras: Query<&mut DistanceFog>,
+) {
+    let Some(fog_def) = selected_def(&registry, &selected).and_then(|d| d.fog.as_ref()) else {
+        return;
+    };
+    for mut fog in &mut cameras {
+        fog.color = rgb(fog_def.color);
+        fog.falloff = FogFalloff::Exponential { density: fog_def.density };
+    }
+}
+
+pub struct AreaPlugin;
+
+impl Plugin for AreaPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SelectedArea>()
+            .init_resource::<AreaBounds>()
+            .init_resource::<Heightfield>()
+            .add_systems(Startup, load_area_registry)
+            .add_systems(
+                OnEnter(State::Playing),
+                (
+                    apply_area_bounds,
+                    apply_area_terrain,
+                    setup_area.after(apply_area_bounds).after(apply_area_terrain),
+                    apply_area_fog.after(setup_camera),
+                ),
+            );
+    }
 }
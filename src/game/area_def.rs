@@ -0,0 +1,555 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::{audio::synth::Synth, game::boundary::Boundary};
+
+/// Directory scanned at startup for `*.ron` area definitions.
+/// The file stem doubles as the area id (`cave.ron` → `"cave"`).
+const AREAS_DIR: &str = "assets/areas";
+
+/// Linear RGB triple as written in the RON files.
+pub type Rgb = (f32, f32, f32);
+
+// ─── Definition types ────────────────────────────────────────────────────────
+
+/// Everything needed to spawn an area — geometry, materials, lights, fog and bounds.
+#[derive(Deserialize, Clone, Debug)]
+pub struct AreaDef {
+    /// Display name shown in the area menu.
+    pub name: String,
+    /// Id of the area whose objective must be completed before this one opens.
+    #[serde(default)]
+    pub requires: Option<String>,
+    pub bounds: BoundsDef,
+    pub floor: FloorDef,
+    #[serde(default)]
+    pub fog: Option<FogDef>,
+    /// Looping background sound while the area is loaded.
+    #[serde(default)]
+    pub ambience: Option<AmbienceDef>,
+    /// Reverb applied to sounds played in the area, worked out from the size
+    /// of its camera bounds unless overridden here.
+    #[serde(default)]
+    pub acoustics: AcousticsDef,
+    /// Score for the area, layered up as its objectives progress.
+    #[serde(default)]
+    pub music: Option<MusicDef>,
+    /// Objective HUD styling; `None` for areas with nothing to complete.
+    #[serde(default)]
+    pub hud: Option<HudDef>,
+    /// Goals that must all be met to complete the area.
+    #[serde(default)]
+    pub objectives: Vec<ObjectiveDef>,
+    /// Named materials referenced by `ObjectDef::material`.
+    #[serde(default)]
+    pub materials: HashMap<String, MaterialDef>,
+    #[serde(default)]
+    pub objects: Vec<ObjectDef>,
+    #[serde(default)]
+    pub directional_lights: Vec<DirectionalLightDef>,
+}
+
+impl AreaDef {
+    /// Reads an area file, rejecting bounds that would trap everything on a
+    /// point or a line.
+    pub fn parse(source: &str) -> Result<Self, String> {
+        let def = ron::from_str::<AreaDef>(source).map_err(|e| e.to_string())?;
+        for (which, bounds) in [("play", &def.bounds.play), ("camera", &def.bounds.camera)] {
+            if let Some(problem) = bounds.problem() {
+                return Err(format!("{which} bounds: {problem}"));
+            }
+        }
+        Ok(def)
+    }
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct BoundsDef {
+    /// Outline for cat movement and object clamping.
+    pub play: BoundaryDef,
+    /// Outline for the camera orbit clamp.
+    pub camera: BoundaryDef,
+}
+
+/// Area outline on the XZ plane. Points are `(x, z)` pairs.
+#[derive(Deserialize, Clone, Debug)]
+pub enum BoundaryDef {
+    /// Circle of this radius around the origin.
+    Circle(f32),
+    /// Straight-edged outline, e.g. a corridor or an L-shaped room.
+    Polygon(Vec<(f32, f32)>),
+    /// Smooth closed curve through these points.
+    Spline(Vec<(f32, f32)>),
+}
+
+impl BoundaryDef {
+    /// Why the outline can't hold anything in, if it can't: a polygon or spline
+    /// needs at least three points, and a circle a positive radius.
+    pub fn problem(&self) -> Option<String> {
+        match self {
+            BoundaryDef::Circle(radius) if *radius <= 0.0 => Some(format!("circle radius {radius}")),
+            BoundaryDef::Polygon(p) | BoundaryDef::Spline(p) if p.len() < 3 => {
+                Some(format!("outline of only {} points", p.len()))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn to_boundary(&self) -> Boundary {
+        let points = |p: &[(f32, f32)]| p.iter().map(|&(x, z)| Vec2::new(x, z)).collect::<Vec<_>>();
+        match self {
+            BoundaryDef::Circle(radius) => Boundary::Circle { center: Vec2::ZERO, radius: *radius },
+            BoundaryDef::Polygon(p) => Boundary::Polygon(points(p)),
+            BoundaryDef::Spline(p) => Boundary::spline(&points(p)),
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct FloorDef {
+    pub radius: f32,
+    pub color: Rgb,
+    #[serde(default = "default_floor_roughness")]
+    pub roughness: f32,
+    pub noise_scale: f32,
+    /// Uneven floor; a flat disc at y = 0 when absent.
+    #[serde(default)]
+    pub terrain: Option<TerrainDef>,
+    /// What the floor sounds like underfoot.
+    #[serde(default)]
+    pub surface: Surface,
+}
+
+/// Material underfoot, which picks the footstep and landing sounds.
+#[derive(Component, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Surface {
+    #[default]
+    Rock,
+    Crystal,
+    Wood,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct TerrainDef {
+    pub source: TerrainSource,
+    /// Largest rise above, or dip below, y = 0.
+    pub amplitude: f32,
+    /// Rounds heights to multiples of this step, turning slopes into ledges.
+    #[serde(default)]
+    pub terrace: Option<f32>,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub enum TerrainSource {
+    /// Smooth value noise; `scale` is the feature size in metres.
+    Seeded { seed: u32, scale: f32 },
+    /// Grayscale image path under `assets/`; black is the lowest point, white the highest.
+    Heightmap(String),
+}
+
+fn default_floor_roughness() -> f32 {
+    0.95
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct FogDef {
+    pub color: Rgb,
+    pub density: f32,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct AmbienceDef {
+    /// Looped for as long as the area is loaded.
+    pub sound: Synth,
+    #[serde(default = "default_gain")]
+    pub volume: f32,
+}
+
+/// Overrides for the acoustics worked out from the area's size; see
+/// `audio::reverb::Acoustics`.
+#[derive(Deserialize, Clone, Copy, Debug, Default)]
+#[serde(default)]
+pub struct AcousticsDef {
+    /// Seconds for the reverb to die away.
+    pub reverb_seconds: Option<f32>,
+    /// Seconds before the first reflection arrives.
+    pub reflections: Option<f32>,
+    /// Share of reverberant sound in the mix, 0..1.
+    pub wet: Option<f32>,
+    /// Cutoff of the low-pass on the reverb, in Hz.
+    pub low_pass: Option<f32>,
+}
+
+/// Adaptive score: loops played together, each fading in once the objectives
+/// have come far enough. Layer changes and stingers wait for the beat.
+#[derive(Deserialize, Clone, Debug)]
+pub struct MusicDef {
+    /// Beats per minute.
+    pub tempo: f32,
+    #[serde(default = "default_beats_per_bar")]
+    pub beats_per_bar: u32,
+    /// Every stem should last a whole number of bars, so they stay in step.
+    pub stems: Vec<StemDef>,
+    /// Played on the next beat whenever an objective moves on.
+    #[serde(default)]
+    pub stinger: Option<Synth>,
+    /// Played on the next bar once every objective is met.
+    #[serde(default)]
+    pub finale: Option<Synth>,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct StemDef {
+    pub sound: Synth,
+    #[serde(default = "default_gain")]
+    pub volume: f32,
+    /// Share of all objective progress, 0..1, at which the layer comes in;
+    /// 0.0 plays from the start.
+    #[serde(default)]
+    pub from: f32,
+}
+
+fn default_beats_per_bar() -> u32 {
+    4
+}
+
+fn default_gain() -> f32 {
+    1.0
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct HudDef {
+    pub color: Rgb,
+    /// Heading of the results panel once the area is complete.
+    pub win_text: String,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct ObjectiveDef {
+    /// Prefix of the progress text, e.g. "Embers" → "Embers: 1/3".
+    pub label: String,
+    pub goal: GoalDef,
+}
+
+/// What an objective counts. Totals come from the spawned objects unless `count` is set.
+#[derive(Deserialize, Clone, Debug)]
+pub enum GoalDef {
+    /// Light objects of this kind (`Ember` or `Crystal`).
+    Light {
+        kind: InteractKind,
+        #[serde(default)]
+        count: Option<u32>,
+    },
+    /// Tip tippable objects over.
+    Tip {
+        #[serde(default)]
+        count: Option<u32>,
+    },
+    /// Roll a pushable object to within `radius` of the object named `target`.
+    PushOnto { target: String, radius: f32 },
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub enum MaterialDef {
+    /// `RockMaterial` — the noise-shaded stone used for cave surfaces.
+    Rock {
+        color: Rgb,
+        roughness: f32,
+        noise_scale: f32,
+        #[serde(default)]
+        double_sided: bool,
+        /// Cull front faces instead of back faces (for surfaces seen from inside).
+        #[serde(default)]
+        cull_front: bool,
+    },
+    /// Plain `StandardMaterial`. Interactables must use this kind so their
+    /// emissive glow can be driven at runtime.
+    Standard {
+        color: Rgb,
+        #[serde(default = "default_alpha")]
+        alpha: f32,
+        #[serde(default)]
+        emissive: Rgb,
+        #[serde(default = "default_roughness")]
+        roughness: f32,
+        #[serde(default = "default_reflectance")]
+        reflectance: f32,
+    },
+}
+
+fn default_alpha() -> f32 {
+    1.0
+}
+
+fn default_roughness() -> f32 {
+    0.5
+}
+
+fn default_reflectance() -> f32 {
+    0.5
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub enum MeshDef {
+    Cuboid(f32, f32, f32),
+    Sphere(f32),
+    Circle(f32),
+    Cone { radius: f32, height: f32 },
+    Frustum { bottom: f32, top: f32, height: f32 },
+    Cylinder { radius: f32, half_height: f32 },
+}
+
+impl MeshDef {
+    pub fn to_mesh(&self) -> Mesh {
+        match *self {
+            MeshDef::Cuboid(x, y, z) => Cuboid::new(x, y, z).into(),
+            MeshDef::Sphere(radius) => Sphere::new(radius).into(),
+            MeshDef::Circle(radius) => Circle::new(radius).into(),
+            MeshDef::Cone { radius, height } => Cone { radius, height }.into(),
+            MeshDef::Frustum { bottom, top, height } => ConicalFrustum {
+                radius_bottom: bottom,
+                radius_top: top,
+                height,
+            }
+            .into(),
+            MeshDef::Cylinder { radius, half_height } => Cylinder { radius, half_height }.into(),
+        }
+    }
+
+    /// Half of the unrotated mesh's vertical extent — every shape is centred on its origin.
+    pub fn half_height(&self) -> f32 {
+        match *self {
+            MeshDef::Cuboid(_, y, _) => y * 0.5,
+            MeshDef::Sphere(radius) => radius,
+            MeshDef::Circle(_) => 0.0,
+            MeshDef::Cone { height, .. } | MeshDef::Frustum { height, .. } => height * 0.5,
+            MeshDef::Cylinder { half_height, .. } => half_height,
+        }
+    }
+}
+
+/// Collision shape. The vertical extent comes from the object's mesh.
+#[derive(Deserialize, Clone, Copy, Debug)]
+pub enum ColliderDef {
+    /// Upright cylinder of this radius.
+    Circle(f32),
+    /// Half extents along the object's local X and Z; follows its full rotation.
+    Box(f32, f32),
+    /// Half length along the object's local Z, then radius.
+    Capsule(f32, f32),
+}
+
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InteractKind {
+    /// Slides away from the cat.
+    Push,
+    /// Tips over once.
+    Tip,
+    /// Cave ember — lit by interacting.
+    Ember,
+    /// Crystal node — lit by interacting.
+    Crystal,
+    /// Hidden collectible — vanishes once found and is remembered in the save.
+    Secret,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct InteractDef {
+    pub radius: f32,
+    pub kind: InteractKind,
+}
+
+/// Sends the cat to another area when it steps within `radius` of the object.
+#[derive(Deserialize, Clone, Debug)]
+pub struct PortalDef {
+    /// Id of the destination area.
+    pub to: String,
+    pub radius: f32,
+    /// XZ point in the destination area where the cat comes out.
+    pub arrive: (f32, f32),
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct PointLightDef {
+    pub color: Rgb,
+    pub intensity: f32,
+    pub range: f32,
+    #[serde(default)]
+    pub shadows: bool,
+    #[serde(default)]
+    pub shadow_depth_bias: Option<f32>,
+}
+
+impl PointLightDef {
+    pub fn to_light(&self) -> PointLight {
+        let mut light = PointLight {
+            color: rgb(self.color),
+            intensity: self.intensity,
+            range: self.range,
+            shadows_enabled: self.shadows,
+            ..default()
+        };
+        if let Some(bias) = self.shadow_depth_bias {
+            light.shadow_depth_bias = bias;
+        }
+        light
+    }
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct DirectionalLightDef {
+    pub color: Rgb,
+    pub illuminance: f32,
+    /// XYZ Euler angles in degrees.
+    pub rotation: (f32, f32, f32),
+}
+
+impl DirectionalLightDef {
+    pub fn transform(&self) -> Transform {
+        Transform::from_rotation(euler_degrees(self.rotation))
+    }
+}
+
+/// A single mesh placed in the area.
+#[derive(Deserialize, Clone, Debug)]
+pub struct ObjectDef {
+    pub name: String,
+    pub mesh: MeshDef,
+    /// Key into `AreaDef::materials`.
+    pub material: String,
+    pub at: (f32, f32, f32),
+    /// XYZ Euler angles in degrees.
+    #[serde(default)]
+    pub rotation: (f32, f32, f32),
+    /// Point light attached as a child of the object.
+    #[serde(default)]
+    pub light: Option<PointLightDef>,
+    #[serde(default)]
+    pub collider: Option<ColliderDef>,
+    #[serde(default)]
+    pub interact: Option<InteractDef>,
+    #[serde(default)]
+    pub portal: Option<PortalDef>,
+    /// What the object's top sounds like underfoot; the floor's surface when absent.
+    #[serde(default)]
+    pub surface: Option<Surface>,
+}
+
+impl AreaDef {
+    /// Number of secrets hidden in the area.
+    pub fn secret_count(&self) -> usize {
+        self.objects
+            .iter()
+            .filter(|o| o.interact.as_ref().is_some_and(|i| i.kind == InteractKind::Secret))
+            .count()
+    }
+}
+
+impl ObjectDef {
+    pub fn transform(&self) -> Transform {
+        Transform::from_xyz(self.at.0, self.at.1, self.at.2)
+            .with_rotation(euler_degrees(self.rotation))
+    }
+}
+
+pub fn rgb((r, g, b): Rgb) -> Color {
+    Color::srgb(r, g, b)
+}
+
+fn euler_degrees((x, y, z): (f32, f32, f32)) -> Quat {
+    Quat::from_euler(EulerRot::XYZ, x.to_radians(), y.to_radians(), z.to_radians())
+}
+
+// ─── Registry ────────────────────────────────────────────────────────────────
+
+/// All area definitions found in `assets/areas`, sorted by id.
+#[derive(Resource, Default)]
+pub struct AreaRegistry {
+    areas: Vec<(String, AreaDef)>,
+}
+
+impl AreaRegistry {
+    pub fn load() -> Self {
+        let mut areas = Vec::new();
+        let Ok(entries) = std::fs::read_dir(AREAS_DIR) else {
+            warn!("No area directory at {AREAS_DIR}");
+            return Self::from_areas(areas);
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("ron") {
+                continue;
+            }
+            let Some(id) = path.file_stem().and_then(|s| s.to_str()) else { continue };
+            let parsed = std::fs::read_to_string(&path)
+                .map_err(|e| e.to_string())
+                .and_then(|s| AreaDef::parse(&s));
+            match parsed {
+                Ok(def) => areas.push((id.to_string(), def)),
+                Err(e) => warn!("Skipping area {}: {e}", path.display()),
+            }
+        }
+        Self::from_areas(areas)
+    }
+
+    /// Registry of already parsed `(id, definition)` pairs.
+    pub fn from_areas(mut areas: Vec<(String, AreaDef)>) -> Self {
+        areas.sort_by(|a, b| a.0.cmp(&b.0));
+        Self { areas }
+    }
+
+    pub fn get(&self, id: &str) -> Option<&AreaDef> {
+        self.areas.iter().find(|(area_id, _)| area_id == id).map(|(_, def)| def)
+    }
+
+    /// Iterates `(id, definition)` pairs in menu order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &AreaDef)> {
+        self.areas.iter().map(|(id, def)| (id.as_str(), def))
+    }
+
+    pub fn first_id(&self) -> Option<&str> {
+        self.areas.first().map(|(id, _)| id.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn area_with_play(play: &str) -> Result<AreaDef, String> {
+        AreaDef::parse(&format!(
+            "AreaDef(
+                name: \"Test\",
+                bounds: (play: {play}, camera: Circle(5.0)),
+                floor: (radius: 4.0, color: (0.1, 0.1, 0.1), noise_scale: 1.0),
+            )"
+        ))
+    }
+
+    #[test]
+    fn two_point_outlines_are_rejected() {
+        let polygon = area_with_play("Polygon([(-1.0, 0.0), (1.0, 0.0)])");
+        assert!(polygon.unwrap_err().contains("play bounds"));
+        assert!(area_with_play("Spline([(-1.0, 0.0), (1.0, 0.0)])").is_err());
+        assert!(area_with_play("Circle(0.0)").is_err());
+    }
+
+    #[test]
+    fn closed_outlines_are_accepted() {
+        assert!(area_with_play("Polygon([(-1.0, 0.0), (1.0, 0.0), (0.0, 1.0)])").is_ok());
+        assert!(area_with_play("Spline([(-1.0, 0.0), (1.0, 0.0), (0.0, 1.0)])").is_ok());
+        assert!(area_with_play("Circle(3.0)").is_ok());
+    }
+
+    #[test]
+    fn shipped_areas_all_load() {
+        for file in ["cave", "crystal_cavern", "hub"] {
+            let source = std::fs::read_to_string(format!("{AREAS_DIR}/{file}.ron")).unwrap();
+            if let Err(e) = AreaDef::parse(&source) {
+                panic!("{file}: {e}");
+            }
+        }
+    }
+}
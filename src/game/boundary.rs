@@ -0,0 +1,184 @@
+use bevy::prelude::*;
+
+/// Line segments generated per control point when a spline is flattened.
+const SPLINE_SEGMENTS: usize = 8;
+
+/// Closed XZ outline that keeps the cat, bodies or camera inside an area.
+#[derive(Clone, Debug)]
+pub enum Boundary {
+    Circle { center: Vec2, radius: f32 },
+    /// Simple polygon; vertices in order, the last joining back to the first.
+    Polygon(Vec<Vec2>),
+}
+
+impl Boundary {
+    /// Flattens a closed Catmull-Rom spline through `points` into a polygon.
+    pub fn spline(points: &[Vec2]) -> Self {
+        let n = points.len();
+        if n < 3 {
+            return Boundary::Polygon(points.to_vec());
+        }
+        let mut vertices = Vec::with_capacity(n * SPLINE_SEGMENTS);
+        for i in 0..n {
+            let p0 = points[(i + n - 1) % n];
+            let p1 = points[i];
+            let p2 = points[(i + 1) % n];
+            let p3 = points[(i + 2) % n];
+            for step in 0..SPLINE_SEGMENTS {
+                let t = step as f32 / SPLINE_SEGMENTS as f32;
+                let (t2, t3) = (t * t, t * t * t);
+                vertices.push(
+                    0.5 * (2.0 * p1
+                        + (p2 - p0) * t
+                        + (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * t2
+                        + (3.0 * p1 - p0 - 3.0 * p2 + p3) * t3),
+                );
+            }
+        }
+        Boundary::Polygon(vertices)
+    }
+
+    pub fn contains(&self, point: Vec2) -> bool {
+        match self {
+            Boundary::Circle { center, radius } => point.distance(*center) <= *radius,
+            Boundary::Polygon(vertices) => {
+                // Even-odd rule: count edges crossed by a ray towards +X
+                let mut inside = false;
+                for (a, b) in edges(vertices) {
+                    if (a.y > point.y) != (b.y > point.y) {
+                        let x = a.x + (point.y - a.y) / (b.y - a.y) * (b.x - a.x);
+                        if point.x < x {
+                            inside = !inside;
+                        }
+                    }
+                }
+                inside
+            }
+        }
+    }
+
+    /// Floor area enclosed by the outline.
+    pub fn area(&self) -> f32 {
+        match self {
+            Boundary::Circle { radius, .. } => std::f32::consts::PI * radius * radius,
+            // Shoelace formula
+            Boundary::Polygon(vertices) => {
+                edges(vertices).map(|(a, b)| a.perp_dot(b)).sum::<f32>().abs() * 0.5
+            }
+        }
+    }
+
+    /// Nearest point on the outline itself.
+    pub fn closest_point(&self, point: Vec2) -> Vec2 {
+        match self {
+            Boundary::Circle { center, radius } => {
+                *center + (point - *center).normalize_or(Vec2::X) * *radius
+            }
+            Boundary::Polygon(vertices) => edges(vertices)
+                .map(|(a, b)| {
+                    let ab = b - a;
+                    let t = ((point - a).dot(ab) / ab.length_squared().max(1e-8)).clamp(0.0, 1.0);
+                    a + ab * t
+                })
+                .min_by(|p, q| p.distance_squared(point).total_cmp(&q.distance_squared(point)))
+                .unwrap_or(point),
+        }
+    }
+
+    /// `point` if it is inside, otherwise the nearest point on the outline.
+    pub fn clamp(&self, point: Vec2) -> Vec2 {
+        if self.contains(point) { point } else { self.closest_point(point) }
+    }
+
+    /// Pulls the XZ of `position` back inside. Returns the inward wall normal
+    /// if it had to be moved.
+    pub fn clamp_xz(&self, position: &mut Vec3) -> Option<Vec2> {
+        let xz = position.xz();
+        let clamped = self.clamp(xz);
+        if clamped == xz {
+            return None;
+        }
+        position.x = clamped.x;
+        position.z = clamped.y;
+        Some((clamped - xz).normalize_or_zero())
+    }
+}
+
+fn edges(vertices: &[Vec2]) -> impl Iterator<Item = (Vec2, Vec2)> + '_ {
+    let n = vertices.len();
+    (0..n).map(move |i| (vertices[i], vertices[(i + 1) % n]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The hub's L-shaped walkway, with its concave corner at (3, -1.5).
+    fn hub() -> Boundary {
+        Boundary::Polygon(
+            [(-6.0, -1.5), (3.0, -1.5), (3.0, -5.0), (6.0, -5.0), (6.0, 1.5), (-6.0, 1.5)]
+                .into_iter()
+                .map(|(x, z)| Vec2::new(x, z))
+                .collect(),
+        )
+    }
+
+    fn assert_near(actual: Vec2, expected: Vec2) {
+        assert!((actual - expected).length() < 1e-4, "expected {expected}, got {actual}");
+    }
+
+    #[test]
+    fn concave_corner_is_outside_only_in_its_notch() {
+        let hub = hub();
+        assert!(hub.contains(Vec2::new(2.9, -1.4)));
+        assert!(hub.contains(Vec2::new(3.1, -1.4)));
+        assert!(hub.contains(Vec2::new(3.1, -1.6)));
+        assert!(!hub.contains(Vec2::new(2.9, -1.6)));
+        assert!(!hub.contains(Vec2::new(0.0, -3.0)));
+        assert!(hub.contains(Vec2::new(4.5, -4.0)));
+        assert!(!hub.contains(Vec2::new(-7.0, 0.0)));
+    }
+
+    #[test]
+    fn every_edge_clamps_back_with_an_inward_normal() {
+        let hub = hub();
+        for (outside, on_edge, normal) in [
+            (Vec2::new(0.0, -2.0), Vec2::new(0.0, -1.5), Vec2::Y),
+            (Vec2::new(2.5, -3.0), Vec2::new(3.0, -3.0), Vec2::X),
+            (Vec2::new(4.5, -5.5), Vec2::new(4.5, -5.0), Vec2::Y),
+            (Vec2::new(6.5, -2.0), Vec2::new(6.0, -2.0), Vec2::NEG_X),
+            (Vec2::new(0.0, 2.0), Vec2::new(0.0, 1.5), Vec2::NEG_Y),
+            (Vec2::new(-6.5, 0.0), Vec2::new(-6.0, 0.0), Vec2::X),
+        ] {
+            assert_near(hub.closest_point(outside), on_edge);
+            let mut position = Vec3::new(outside.x, 0.7, outside.y);
+            let wall_normal = hub.clamp_xz(&mut position).expect("outside point should be clamped");
+            assert_near(wall_normal, normal);
+            assert_near(position.xz(), on_edge);
+            assert_eq!(position.y, 0.7);
+        }
+    }
+
+    #[test]
+    fn points_inside_are_left_alone() {
+        let mut position = Vec3::new(4.5, 0.0, -4.0);
+        assert!(hub().clamp_xz(&mut position).is_none());
+        assert_eq!(position, Vec3::new(4.5, 0.0, -4.0));
+    }
+
+    #[test]
+    fn polygon_area_uses_the_shoelace_formula() {
+        assert!((hub().area() - 46.5).abs() < 1e-4);
+    }
+
+    #[test]
+    fn spline_passes_through_its_control_points() {
+        let points = [Vec2::new(-4.0, -3.0), Vec2::new(5.0, -2.0), Vec2::new(4.0, 4.0), Vec2::new(-3.0, 3.0)];
+        let Boundary::Polygon(vertices) = Boundary::spline(&points) else { panic!("expected a polygon") };
+        assert_eq!(vertices.len(), points.len() * SPLINE_SEGMENTS);
+        for (i, point) in points.iter().enumerate() {
+            assert_near(vertices[i * SPLINE_SEGMENTS], *point);
+        }
+        assert!(Boundary::Polygon(vertices).contains(Vec2::ZERO));
+    }
+}
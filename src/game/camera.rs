@@ -1,15 +1,29 @@
-use crate::{characters::Cat, settings::AppSettings};
-use crate::game::area::{AreaBounds, GameEntity};
+use crate::{
+    characters::Cat,
+    input::{Action, Actions},
+    settings::AppSettings,
+};
+use crate::game::{
+    area::{AreaBounds, GameEntity},
+    terrain::Heightfield,
+};
 use bevy::post_process::bloom::{Bloom, BloomCompositeMode, BloomPrefilter};
 use bevy::core_pipeline::tonemapping::Tonemapping;
 use bevy::pbr::{DistanceFog, FogFalloff};
 use bevy::prelude::{
-    ButtonInput, Camera3d, Color, Commands, Component, KeyCode, Msaa, ParamSet, PointLight, Query,
-    Res, Time, Transform, Vec2, Vec3, With, default,
+    Camera3d, Color, Commands, Component, Msaa, ParamSet, PointLight, Query, Res, ResMut, Resource,
+    SpatialListener, Time, Timer, TimerMode, Transform, Vec2, Vec3, With, default,
 };
+use std::f32::consts::PI;
 
 use bevy::input::mouse::AccumulatedMouseMotion;
 
+/// Minimum height of the camera above the floor beneath it.
+const CAMERA_CLEARANCE: f32 = 0.5;
+
+/// Distance between the listener's ears, in metres.
+const EAR_GAP: f32 = 0.3;
+
 #[derive(Component, Clone, Copy)]
 pub struct CameraRig {
     pub yaw: f32,
@@ -17,9 +31,50 @@ pub struct CameraRig {
     pub distance: f32,
 }
 
+impl CameraRig {
+    /// Puts the camera on the rig's orbit around `target`, inside the area's
+    /// camera boundary and above the floor, looking at the target.
+    pub fn place(
+        &self,
+        target: Vec3,
+        camera_transform: &mut Transform,
+        bounds: &AreaBounds,
+        terrain: &Heightfield,
+    ) {
+        let offset = Vec3::new(
+            self.distance * self.pitch.cos() * self.yaw.cos(),
+            self.distance * self.pitch.sin(),
+            self.distance * self.pitch.cos() * self.yaw.sin(),
+        );
+
+        camera_transform.translation = target + offset;
+
+        // Keep camera inside the area's camera boundary
+        bounds.camera.clamp_xz(&mut camera_transform.translation);
+
+        // Stay above the floor on uneven terrain
+        let cam_xz = Vec2::new(camera_transform.translation.x, camera_transform.translation.z);
+        let floor = terrain.height_at(cam_xz) + CAMERA_CLEARANCE;
+        camera_transform.translation.y = camera_transform.translation.y.max(floor);
+
+        camera_transform.look_at(target, Vec3::Y);
+    }
+}
+
 #[derive(Component)]
 pub struct CameraMarkerComponent;
 
+/// Length of the camera swing that opens the completion screen, in seconds.
+const CELEBRATION_SECONDS: f32 = 2.5;
+
+/// Runs the celebratory camera swing; the results panel waits for it to finish.
+#[derive(Resource)]
+pub struct Celebration(pub Timer);
+
+pub fn start_celebration(mut commands: Commands) {
+    commands.insert_resource(Celebration(Timer::from_seconds(CELEBRATION_SECONDS, TimerMode::Once)));
+}
+
 pub fn setup_camera(
     existing: Query<(), With<CameraMarkerComponent>>,
     mut commands: Commands,
@@ -43,6 +98,8 @@ pub fn setup_camera(
         GameEntity,
         CameraMarkerComponent,
         Camera3d::default(),
+        // Positional sounds are heard from here
+        SpatialListener::new(EAR_GAP),
         CameraRig {
             yaw: 0.0,
             pitch: 0.3,
@@ -72,8 +129,9 @@ pub fn setup_camera(
 
 pub fn orbit_camera_keyboard(
     time: Res<Time>,
-    keys: Res<ButtonInput<KeyCode>>,
+    actions: Actions,
     bounds: Res<AreaBounds>,
+    terrain: Res<Heightfield>,
     mut set: ParamSet<(
         Query<(&mut CameraRig, &mut Transform)>,
         Query<&Transform, With<Cat>>,
@@ -91,38 +149,15 @@ pub fn orbit_camera_keyboard(
     let rotate_speed = 1.5;
     let pitch_limit = 0.7;
 
-    if keys.pressed(KeyCode::ArrowLeft) && !keys.pressed(KeyCode::ArrowRight) {
-        rig.yaw += rotate_speed * time.delta_secs();
-    }
-    if keys.pressed(KeyCode::ArrowRight) && !keys.pressed(KeyCode::ArrowLeft) {
-        rig.yaw -= rotate_speed * time.delta_secs();
-    }
-
-    if keys.pressed(KeyCode::ArrowUp) && !keys.pressed(KeyCode::ArrowDown) {
-        rig.pitch = (rig.pitch + rotate_speed * time.delta_secs()).clamp(0.15, pitch_limit);
-    }
-
-    if keys.pressed(KeyCode::ArrowDown) && !keys.pressed(KeyCode::ArrowUp) {
-        rig.pitch = (rig.pitch - rotate_speed * time.delta_secs()).clamp(0.15, pitch_limit);
-    }
+    // Arrow keys and the right stick both orbit the rig
+    let stick = actions.camera_axis();
+    let yaw_input = actions.axis(Action::CameraLeft, Action::CameraRight) + stick.x;
+    let pitch_input = actions.axis(Action::CameraDown, Action::CameraUp) + stick.y;
+    rig.yaw -= yaw_input.clamp(-1.0, 1.0) * rotate_speed * time.delta_secs();
+    rig.pitch = (rig.pitch + pitch_input.clamp(-1.0, 1.0) * rotate_speed * time.delta_secs())
+        .clamp(0.15, pitch_limit);
 
-    let offset = Vec3::new(
-        rig.distance * rig.pitch.cos() * rig.yaw.cos(),
-        rig.distance * rig.pitch.sin(),
-        rig.distance * rig.pitch.cos() * rig.yaw.sin(),
-    );
-
-    camera_transform.translation = target_transform_translation + offset;
-
-    // Keep camera inside the area cylinder
-    let cam_xz = Vec2::new(camera_transform.translation.x, camera_transform.translation.z);
-    if cam_xz.length() > bounds.camera_radius {
-        let clamped = cam_xz.normalize() * bounds.camera_radius;
-        camera_transform.translation.x = clamped.x;
-        camera_transform.translation.z = clamped.y;
-    }
-
-    camera_transform.look_at(target_transform_translation, Vec3::Y);
+    rig.place(target_transform_translation, &mut camera_transform, &bounds, &terrain);
 }
 
 pub fn orbit_camera_mouse(
@@ -133,6 +168,7 @@ pub fn orbit_camera_mouse(
     accumulated_mouse_motion: Res<AccumulatedMouseMotion>,
     settings: Res<AppSettings>,
     bounds: Res<AreaBounds>,
+    terrain: Res<Heightfield>,
 ) {
     let target_transform_translation = set
         .p1()
@@ -151,21 +187,35 @@ pub fn orbit_camera_mouse(
     rig.pitch =
         (rig.pitch + invert * rotate_speed * accumulated_mouse_motion.delta.y).clamp(0.15, pitch_limit);
 
-    let offset = Vec3::new(
-        rig.distance * rig.pitch.cos() * rig.yaw.cos(),
-        rig.distance * rig.pitch.sin(),
-        rig.distance * rig.pitch.cos() * rig.yaw.sin(),
-    );
-
-    camera_transform.translation = target_transform_translation + offset;
+    rig.place(target_transform_translation, &mut camera_transform, &bounds, &terrain);
+}
 
-    // Keep camera inside the area cylinder
-    let cam_xz = Vec2::new(camera_transform.translation.x, camera_transform.translation.z);
-    if cam_xz.length() > bounds.camera_radius {
-        let clamped = cam_xz.normalize() * bounds.camera_radius;
-        camera_transform.translation.x = clamped.x;
-        camera_transform.translation.z = clamped.y;
+/// Swings the rig half a turn around the cat while lifting and drawing in,
+/// easing in and out so it starts and settles gently.
+pub fn celebrate_camera(
+    time: Res<Time>,
+    mut celebration: ResMut<Celebration>,
+    bounds: Res<AreaBounds>,
+    terrain: Res<Heightfield>,
+    mut set: ParamSet<(
+        Query<(&mut CameraRig, &mut Transform)>,
+        Query<&Transform, With<Cat>>,
+    )>,
+) {
+    if celebration.0.is_finished() {
+        return;
     }
+    let ease = |t: f32| t * t * (3.0 - 2.0 * t);
+    let before = ease(celebration.0.fraction());
+    celebration.0.tick(time.delta());
+    let step = ease(celebration.0.fraction()) - before;
+
+    let Ok(target) = set.p1().single().map(|t| t.translation) else { return };
+    let mut query = set.p0();
+    let Ok((mut rig, mut camera_transform)) = query.single_mut() else { return };
 
-    camera_transform.look_at(target_transform_translation, Vec3::Y);
+    rig.yaw += PI * step;
+    rig.pitch = (rig.pitch + 0.3 * step).min(0.7);
+    rig.distance -= 3.0 * step;
+    rig.place(target, &mut camera_transform, &bounds, &terrain);
 }
@@ -0,0 +1,807 @@
+use std::collections::HashMap;
+
+use bevy::{ecs::system::SystemParam, prelude::*};
+
+use crate::{
+    characters::{Cat, CatLocomotion, GRAVITY, move_cat},
+    game::{
+        area::{AreaBounds, Floor},
+        area_def::Surface,
+        physics::{BodyVelocity, PhysicsTranslation},
+        terrain::Heightfield,
+    },
+    state::State,
+};
+
+/// Edge length of a `StaticColliderGrid` cell, in metres.
+const GRID_CELL: f32 = 2.0;
+
+/// Tallest ledge the cat climbs onto without jumping, in metres.
+const STEP_HEIGHT: f32 = 0.25;
+/// Radius of the circle under the cat's paws that must rest on a surface.
+const PROBE_RADIUS: f32 = 0.12;
+
+/// Fraction of the normal speed a body keeps after bouncing.
+const RESTITUTION: f32 = 0.6;
+/// Deceleration of rolling bodies, in m/s².
+const ROLLING_FRICTION: f32 = 4.0;
+/// Downhill acceleration per unit of slope. A solid sphere rolling without
+/// slipping keeps 5/7 of g, the rest going into its spin.
+const SLOPE_ACCELERATION: f32 = -GRAVITY * 5.0 / 7.0;
+
+// ─── Components ──────────────────────────────────────────────────────────────
+
+/// Shape of a `Collider`, in the entity's local space.
+#[derive(Clone, Copy, Debug)]
+pub enum ColliderShape {
+    /// Upright cylinder.
+    Circle { radius: f32, half_height: f32 },
+    /// Oriented box. Its footprint and height follow the entity's full rotation,
+    /// so a tipped crate collides on its side.
+    Box { half_extents: Vec3 },
+    /// Segment along the entity's local Z swept by `radius` — long bodies like the cat.
+    Capsule { half_length: f32, radius: f32, half_height: f32 },
+}
+
+/// Solid collider — prevents the cat from walking through.
+/// Only blocks while vertical spans overlap, so low rocks can be jumped over
+/// and high lintels walked under.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct Collider {
+    pub shape: ColliderShape,
+    /// Height of the shape's centre above the entity's translation.
+    pub elevation: f32,
+}
+
+impl ColliderShape {
+    /// Radius of a circle around the centre that encloses the footprint at any rotation.
+    pub fn reach(&self) -> f32 {
+        match *self {
+            ColliderShape::Circle { radius, .. } => radius,
+            ColliderShape::Box { half_extents } => half_extents.length(),
+            ColliderShape::Capsule { half_length, radius, .. } => half_length + radius,
+        }
+    }
+}
+
+impl Collider {
+    /// Places the collider at `translation` and `rotation` in the world.
+    pub fn place(&self, translation: Vec3, rotation: Quat) -> PlacedCollider {
+        let center = translation + Vec3::Y * self.elevation;
+        let (footprint, half_height) = match self.shape {
+            ColliderShape::Circle { radius, half_height } => {
+                let c = center.xz();
+                (Footprint::Capsule { a: c, b: c, radius }, half_height)
+            }
+            ColliderShape::Box { half_extents } => {
+                let axes = [rotation * Vec3::X, rotation * Vec3::Y, rotation * Vec3::Z];
+                let half = half_extents.to_array();
+                // Whichever local axis points most upward sets the height;
+                // the other two span the footprint
+                let up = (0..3)
+                    .max_by(|&i, &j| axes[i].y.abs().total_cmp(&axes[j].y.abs()))
+                    .unwrap_or(1);
+                let [i, j] = match up {
+                    0 => [1, 2],
+                    1 => [0, 2],
+                    _ => [0, 1],
+                };
+                let axis_i = axes[i].xz().normalize_or(Vec2::X);
+                let axis_j = axis_i.perp();
+                let half_height = (0..3).map(|k| axes[k].y.abs() * half[k]).sum();
+                let footprint = Footprint::Rect {
+                    center: center.xz(),
+                    axes: [axis_i, axis_j],
+                    half: Vec2::new(half[i], half[j]),
+                };
+                (footprint, half_height)
+            }
+            ColliderShape::Capsule { half_length, radius, half_height } => {
+                let offset = (rotation * Vec3::Z * half_length).xz();
+                let c = center.xz();
+                (Footprint::Capsule { a: c - offset, b: c + offset, radius }, half_height)
+            }
+        };
+        PlacedCollider {
+            footprint,
+            bottom: center.y - half_height,
+            top: center.y + half_height,
+        }
+    }
+}
+
+// ─── World-space shapes ──────────────────────────────────────────────────────
+
+/// XZ outline of a placed collider.
+#[derive(Clone, Copy, Debug)]
+pub enum Footprint {
+    /// Segment swept by `radius` — a circle when both ends meet.
+    Capsule { a: Vec2, b: Vec2, radius: f32 },
+    /// Rectangle with orthonormal `axes` and `half` extents along them.
+    Rect { center: Vec2, axes: [Vec2; 2], half: Vec2 },
+}
+
+/// A collider resolved into world space for one collision test.
+#[derive(Clone, Copy, Debug)]
+pub struct PlacedCollider {
+    pub footprint: Footprint,
+    pub bottom: f32,
+    pub top: f32,
+}
+
+impl PlacedCollider {
+    fn overlaps_height(&self, other: &PlacedCollider) -> bool {
+        self.bottom < other.top && self.top > other.bottom
+    }
+
+    /// Smallest XZ displacement that moves `self` out of `other`,
+    /// or `None` if they don't overlap.
+    pub fn penetration(&self, other: &PlacedCollider) -> Option<Vec2> {
+        if !self.overlaps_height(other) {
+            return None;
+        }
+        self.footprint.penetration(&other.footprint)
+    }
+}
+
+impl Footprint {
+    /// Smallest XZ displacement that moves `self` out of `other`, ignoring height.
+    pub fn penetration(&self, other: &Footprint) -> Option<Vec2> {
+        match (*self, *other) {
+            (Footprint::Capsule { a, b, radius }, Footprint::Capsule { a: c, b: d, radius: r }) => {
+                let (p, q) = closest_points_on_segments(a, b, c, d);
+                push_apart(p - q, radius + r)
+            }
+            (Footprint::Capsule { a, b, radius }, Footprint::Rect { center, axes, half }) => {
+                capsule_out_of_rect(a, b, radius, center, axes, half)
+            }
+            (Footprint::Rect { center, axes, half }, Footprint::Capsule { a, b, radius }) => {
+                capsule_out_of_rect(a, b, radius, center, axes, half).map(|push| -push)
+            }
+            (
+                Footprint::Rect { center, axes, half },
+                Footprint::Rect { center: other_center, axes: other_axes, half: other_half },
+            ) => rect_out_of_rect(center, axes, half, other_center, other_axes, other_half),
+        }
+    }
+}
+
+/// Push of length `min_dist - |diff|` along `diff`, if closer than `min_dist`.
+fn push_apart(diff: Vec2, min_dist: f32) -> Option<Vec2> {
+    let dist = diff.length();
+    if dist >= min_dist {
+        return None;
+    }
+    // Exactly coincident — any direction will do
+    let dir = if dist < 1e-5 { Vec2::X } else { diff / dist };
+    Some(dir * (min_dist - dist))
+}
+
+/// Closest pair of points between segments `a..b` and `c..d`.
+fn closest_points_on_segments(a: Vec2, b: Vec2, c: Vec2, d: Vec2) -> (Vec2, Vec2) {
+    let d1 = b - a;
+    let d2 = d - c;
+    let r = a - c;
+    let len1 = d1.length_squared();
+    let len2 = d2.length_squared();
+    let f = d2.dot(r);
+
+    let (s, t) = if len1 <= 1e-8 && len2 <= 1e-8 {
+        (0.0, 0.0)
+    } else if len1 <= 1e-8 {
+        (0.0, (f / len2).clamp(0.0, 1.0))
+    } else {
+        let e = d1.dot(r);
+        if len2 <= 1e-8 {
+            ((-e / len1).clamp(0.0, 1.0), 0.0)
+        } else {
+            let cross = d1.dot(d2);
+            let denom = len1 * len2 - cross * cross;
+            let mut s = if denom > 1e-8 { ((cross * f - e * len2) / denom).clamp(0.0, 1.0) } else { 0.0 };
+            let mut t = (cross * s + f) / len2;
+            if t < 0.0 {
+                t = 0.0;
+                s = (-e / len1).clamp(0.0, 1.0);
+            } else if t > 1.0 {
+                t = 1.0;
+                s = ((cross - e) / len1).clamp(0.0, 1.0);
+            }
+            (s, t)
+        }
+    };
+    (a + d1 * s, c + d2 * t)
+}
+
+/// Part of segment `a..b` inside the box `-half..=half`, as a parameter range.
+fn clip_segment_to_box(a: Vec2, b: Vec2, half: Vec2) -> Option<(f32, f32)> {
+    let dir = b - a;
+    let (mut t0, mut t1) = (0.0_f32, 1.0_f32);
+    for axis in 0..2 {
+        if dir[axis].abs() < 1e-8 {
+            if a[axis].abs() > half[axis] {
+                return None;
+            }
+            continue;
+        }
+        let near = (-half[axis] - a[axis]) / dir[axis];
+        let far = (half[axis] - a[axis]) / dir[axis];
+        t0 = t0.max(near.min(far));
+        t1 = t1.min(near.max(far));
+        if t0 > t1 {
+            return None;
+        }
+    }
+    Some((t0, t1))
+}
+
+fn capsule_out_of_rect(
+    a: Vec2,
+    b: Vec2,
+    radius: f32,
+    center: Vec2,
+    axes: [Vec2; 2],
+    half: Vec2,
+) -> Option<Vec2> {
+    let to_local = |p: Vec2| Vec2::new((p - center).dot(axes[0]), (p - center).dot(axes[1]));
+    let (la, lb) = (to_local(a), to_local(b));
+
+    let push = if let Some((t0, t1)) = clip_segment_to_box(la, lb, half) {
+        // The core segment is inside — leave through the face nearest its buried part
+        let p = la.lerp(lb, (t0 + t1) * 0.5);
+        let depth = half - p.abs() + Vec2::splat(radius);
+        if depth.x < depth.y {
+            Vec2::new(depth.x * p.x.signum(), 0.0)
+        } else {
+            Vec2::new(0.0, depth.y * p.y.signum())
+        }
+    } else {
+        let corners = [
+            Vec2::new(-half.x, -half.y),
+            Vec2::new(half.x, -half.y),
+            Vec2::new(half.x, half.y),
+            Vec2::new(-half.x, half.y),
+        ];
+        let diff = (0..4)
+            .map(|i| {
+                let (p, q) = closest_points_on_segments(la, lb, corners[i], corners[(i + 1) % 4]);
+                p - q
+            })
+            .min_by(|x, y| x.length_squared().total_cmp(&y.length_squared()))?;
+        push_apart(diff, radius)?
+    };
+    Some(axes[0] * push.x + axes[1] * push.y)
+}
+
+/// Separating-axis test between two rectangles, pushing the first out of the second.
+fn rect_out_of_rect(
+    center: Vec2,
+    axes: [Vec2; 2],
+    half: Vec2,
+    other_center: Vec2,
+    other_axes: [Vec2; 2],
+    other_half: Vec2,
+) -> Option<Vec2> {
+    let extent = |axes: [Vec2; 2], half: Vec2, n: Vec2| {
+        half.x * axes[0].dot(n).abs() + half.y * axes[1].dot(n).abs()
+    };
+    let offset = center - other_center;
+    let mut best: Option<Vec2> = None;
+    for n in axes.into_iter().chain(other_axes) {
+        let overlap = extent(axes, half, n) + extent(other_axes, other_half, n) - offset.dot(n).abs();
+        if overlap <= 0.0 {
+            return None;
+        }
+        if best.is_none_or(|b| overlap < b.length()) {
+            let sign = if offset.dot(n) < 0.0 { -1.0 } else { 1.0 };
+            best = Some(n * sign * overlap);
+        }
+    }
+    best
+}
+
+// ─── Static collider grid ────────────────────────────────────────────────────
+
+/// Spatial hash of every collider that never translates, so each fixed step
+/// only tests the level geometry near the cat.
+#[derive(Resource, Default)]
+pub struct StaticColliderGrid {
+    cells: HashMap<IVec2, Vec<Entity>>,
+}
+
+impl StaticColliderGrid {
+    fn cell(point: Vec2) -> IVec2 {
+        (point / GRID_CELL).floor().as_ivec2()
+    }
+
+    fn insert(&mut self, entity: Entity, center: Vec2, reach: f32) {
+        let min = Self::cell(center - Vec2::splat(reach));
+        let max = Self::cell(center + Vec2::splat(reach));
+        for x in min.x..=max.x {
+            for y in min.y..=max.y {
+                self.cells.entry(IVec2::new(x, y)).or_default().push(entity);
+            }
+        }
+    }
+
+    /// Colliders whose cells touch the square of half-size `reach` around `center`.
+    pub fn near(&self, center: Vec2, reach: f32) -> Vec<Entity> {
+        let min = Self::cell(center - Vec2::splat(reach));
+        let max = Self::cell(center + Vec2::splat(reach));
+        let mut found = Vec::new();
+        for x in min.x..=max.x {
+            for y in min.y..=max.y {
+                if let Some(entities) = self.cells.get(&IVec2::new(x, y)) {
+                    found.extend_from_slice(entities);
+                }
+            }
+        }
+        found.sort_unstable();
+        found.dedup();
+        found
+    }
+}
+
+/// Downward probe for the surface under the cat's paws.
+#[derive(SystemParam)]
+pub struct GroundProbe<'w, 's> {
+    terrain: Res<'w, Heightfield>,
+    grid: Res<'w, StaticColliderGrid>,
+    statics: Query<
+        'w,
+        's,
+        (&'static Transform, &'static Collider, Option<&'static Surface>),
+        Without<PhysicsTranslation>,
+    >,
+    floor: Query<'w, 's, &'static Surface, With<Floor>>,
+}
+
+impl GroundProbe<'_, '_> {
+    /// True if the floor at `feet` rises more than a step above them — a ledge to jump.
+    pub fn is_ledge(&self, feet: Vec3) -> bool {
+        self.terrain.height_at(feet.xz()) > feet.y + STEP_HEIGHT
+    }
+
+    /// Height of the highest static top under `feet` that is no more than a step
+    /// above them — the terrain if nothing is there.
+    pub fn ground_below(&self, feet: Vec3) -> f32 {
+        self.top_below(feet)
+            .map_or_else(|| self.terrain.height_at(feet.xz()), |(top, _)| top)
+    }
+
+    /// What the ground under `feet` is made of: the static top they rest on if it
+    /// is tagged with a surface, otherwise the area's floor.
+    pub fn surface_below(&self, feet: Vec3) -> Surface {
+        self.top_below(feet)
+            .and_then(|(_, surface)| surface)
+            .or_else(|| self.floor.iter().next().copied())
+            .unwrap_or_default()
+    }
+
+    /// The static top that `ground_below` lands on, if one rises above the terrain.
+    fn top_below(&self, feet: Vec3) -> Option<(f32, Option<Surface>)> {
+        let probe = Footprint::Capsule { a: feet.xz(), b: feet.xz(), radius: PROBE_RADIUS };
+        let mut ground = self.terrain.height_at(feet.xz());
+        let mut found = None;
+        for entity in self.grid.near(feet.xz(), PROBE_RADIUS) {
+            let Ok((transform, collider, surface)) = self.statics.get(entity) else { continue };
+            let placed = collider.place(transform.translation, transform.rotation);
+            if placed.top > ground
+                && placed.top <= feet.y + STEP_HEIGHT
+                && probe.penetration(&placed.footprint).is_some()
+            {
+                ground = placed.top;
+                found = Some((ground, surface.copied()));
+            }
+        }
+        found
+    }
+}
+
+/// Rebuilds the grid whenever static colliders are spawned or despawned. Runs
+/// every frame, in any state, so no removal is missed on frames without a
+/// fixed step or while an area swap is under way.
+fn rebuild_static_grid(
+    added: Query<(), Added<Collider>>,
+    mut removed: RemovedComponents<Collider>,
+    colliders: Query<(Entity, &Transform, &Collider), Without<PhysicsTranslation>>,
+    mut grid: ResMut<StaticColliderGrid>,
+) {
+    let any_removed = removed.read().count() > 0;
+    if added.is_empty() && !any_removed {
+        return;
+    }
+    grid.cells.clear();
+    for (entity, transform, collider) in &colliders {
+        grid.insert(entity, transform.translation.xz(), collider.shape.reach());
+    }
+}
+
+// ─── Collision resolution ────────────────────────────────────────────────────
+
+/// Removes the part of `velocity` heading into a surface with outward normal `normal`,
+/// reflecting it with `RESTITUTION`.
+fn bounce(velocity: &mut Vec2, normal: Vec2) {
+    let into = velocity.dot(normal);
+    if into < 0.0 {
+        *velocity -= normal * into * (1.0 + RESTITUTION);
+    }
+}
+
+/// Removes the horizontal part of `velocity` heading into a surface with outward
+/// normal `normal`, so the cat slides along walls instead of pressing into them.
+pub fn slide(velocity: &mut Vec3, normal: Vec2) {
+    let into = velocity.xz().dot(normal);
+    if into < 0.0 {
+        velocity.x -= normal.x * into;
+        velocity.z -= normal.y * into;
+    }
+}
+
+/// Pushes the cat out of any overlapping `Collider` entities each fixed step.
+/// Static geometry and the area wall push the cat fully and stop its speed into
+/// them; pushable bodies share the displacement and pick up the cat's speed along
+/// the contact normal.
+pub fn resolve_collisions(
+    bounds: Res<AreaBounds>,
+    grid: Res<StaticColliderGrid>,
+    mut cats: Query<
+        (&mut PhysicsTranslation, &Transform, &Collider, &mut CatLocomotion),
+        With<Cat>,
+    >,
+    statics: Query<(&Transform, &Collider), Without<PhysicsTranslation>>,
+    mut bodies: Query<
+        (&Transform, &Collider, &mut PhysicsTranslation, Option<&mut BodyVelocity>),
+        Without<Cat>,
+    >,
+) {
+    let Ok((mut cat, cat_transform, cat_collider, mut locomotion)) = cats.single_mut() else {
+        return;
+    };
+    let cat_rotation = cat_transform.rotation;
+
+    for entity in grid.near(cat.0.xz(), cat_collider.shape.reach()) {
+        let Ok((transform, collider)) = statics.get(entity) else { continue };
+        let placed = collider.place(transform.translation, transform.rotation);
+        let cat_placed = cat_collider.place(cat.0, cat_rotation);
+        if let Some(push) = cat_placed.penetration(&placed) {
+            cat.0.x += push.x;
+            cat.0.z += push.y;
+            slide(&mut locomotion.velocity, push.normalize_or_zero());
+        }
+    }
+
+    for (transform, collider, mut body, velocity) in &mut bodies {
+        let placed = collider.place(body.0, transform.rotation);
+        let cat_placed = cat_collider.place(cat.0, cat_rotation);
+        let Some(push) = cat_placed.penetration(&placed) else { continue };
+        // Split displacement 50/50 so neither sinks into the other
+        cat.0.x += push.x * 0.5;
+        cat.0.z += push.y * 0.5;
+        body.0.x -= push.x * 0.5;
+        body.0.z -= push.y * 0.5;
+        bounds.play.clamp_xz(&mut body.0);
+
+        // The body is shoved at least as fast as the cat walks into it
+        if let Some(mut velocity) = velocity {
+            let normal = -push.normalize_or_zero();
+            let shove = locomotion.velocity.xz().dot(normal) - velocity.0.dot(normal);
+            if shove > 0.0 {
+                velocity.0 += normal * shove;
+            }
+        }
+    }
+
+    if let Some(wall_normal) = bounds.play.clamp_xz(&mut cat.0) {
+        slide(&mut locomotion.velocity, wall_normal);
+    }
+}
+
+/// Moves pushable bodies by their velocity with rolling friction and down slopes,
+/// rolls sphere meshes to match, and bounces them off static colliders, the area
+/// wall and each other.
+fn step_bodies(
+    time: Res<Time>,
+    bounds: Res<AreaBounds>,
+    terrain: Res<Heightfield>,
+    grid: Res<StaticColliderGrid>,
+    statics: Query<(&Transform, &Collider), Without<PhysicsTranslation>>,
+    mut bodies: Query<(&mut PhysicsTranslation, &mut BodyVelocity, &mut Transform, &Collider)>,
+) {
+    let dt = time.delta_secs();
+
+    for (mut position, mut velocity, mut transform, collider) in &mut bodies {
+        velocity.0 -= terrain.gradient_at(position.0.xz()) * SLOPE_ACCELERATION * dt;
+        let speed = velocity.0.length();
+        if speed > 0.0 {
+            velocity.0 *= (speed - ROLLING_FRICTION * dt).max(0.0) / speed;
+        }
+        let step = velocity.0 * dt;
+        position.0.x += step.x;
+        position.0.z += step.y;
+        // Rest the body's underside on the floor
+        let rest = -collider.place(Vec3::ZERO, transform.rotation).bottom;
+        position.0.y = terrain.height_at(position.0.xz()) + rest;
+
+        // Roll without slipping: one radius travelled turns the sphere one radian
+        if let ColliderShape::Circle { radius, .. } = collider.shape
+            && step != Vec2::ZERO
+            && radius > 0.0
+        {
+            let axis = Vec3::new(step.y, 0.0, -step.x).normalize();
+            transform.rotate(Quat::from_axis_angle(axis, step.length() / radius));
+        }
+
+        for entity in grid.near(position.0.xz(), collider.shape.reach()) {
+            let Ok((static_transform, static_collider)) = statics.get(entity) else { continue };
+            let placed = static_collider.place(static_transform.translation, static_transform.rotation);
+            let body_placed = collider.place(position.0, transform.rotation);
+            if let Some(push) = body_placed.penetration(&placed) {
+                position.0.x += push.x;
+                position.0.z += push.y;
+                bounce(&mut velocity.0, push.normalize_or_zero());
+            }
+        }
+
+        if let Some(wall_normal) = bounds.play.clamp_xz(&mut position.0) {
+            bounce(&mut velocity.0, wall_normal);
+        }
+    }
+
+    // Equal-mass bodies split the overlap and trade their normal speeds
+    let mut pairs = bodies.iter_combinations_mut();
+    while let Some([(mut pos_a, mut vel_a, tf_a, col_a), (mut pos_b, mut vel_b, tf_b, col_b)]) =
+        pairs.fetch_next()
+    {
+        let placed_a = col_a.place(pos_a.0, tf_a.rotation);
+        let placed_b = col_b.place(pos_b.0, tf_b.rotation);
+        let Some(push) = placed_a.penetration(&placed_b) else { continue };
+        pos_a.0.x += push.x * 0.5;
+        pos_a.0.z += push.y * 0.5;
+        pos_b.0.x -= push.x * 0.5;
+        pos_b.0.z -= push.y * 0.5;
+
+        let normal = push.normalize_or_zero();
+        let closing = (vel_a.0 - vel_b.0).dot(normal);
+        if closing < 0.0 {
+            let impulse = normal * closing * (1.0 + RESTITUTION) * 0.5;
+            vel_a.0 -= impulse;
+            vel_b.0 += impulse;
+        }
+    }
+}
+
+pub struct CollisionPlugin;
+
+impl Plugin for CollisionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<StaticColliderGrid>()
+            .add_systems(
+                RunFixedMainLoop,
+                rebuild_static_grid.in_set(RunFixedMainLoopSystems::BeforeFixedMainLoop),
+            )
+            .add_systems(
+                FixedUpdate,
+                (resolve_collisions.after(move_cat), step_bodies.after(resolve_collisions))
+                    .run_if(in_state(State::Playing)),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        f32::consts::{FRAC_1_SQRT_2, FRAC_PI_4},
+        time::Duration,
+    };
+
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+    use crate::game::boundary::Boundary;
+
+    const AXES: [Vec2; 2] = [Vec2::X, Vec2::Y];
+    /// Axes of a box turned 45° about Y.
+    const DIAGONAL: [Vec2; 2] =
+        [Vec2::new(FRAC_1_SQRT_2, FRAC_1_SQRT_2), Vec2::new(-FRAC_1_SQRT_2, FRAC_1_SQRT_2)];
+
+    fn assert_near(actual: Vec2, expected: Vec2) {
+        assert!((actual - expected).length() < 1e-4, "expected {expected}, got {actual}");
+    }
+
+    fn circle(center: Vec2, radius: f32) -> Footprint {
+        Footprint::Capsule { a: center, b: center, radius }
+    }
+
+    #[test]
+    fn parallel_segments_meet_square_on() {
+        let (p, q) = closest_points_on_segments(
+            Vec2::new(0.0, 0.0),
+            Vec2::new(2.0, 0.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(3.0, 1.0),
+        );
+        assert!(((p - q).length() - 1.0).abs() < 1e-5);
+        assert!((p.x - q.x).abs() < 1e-5);
+        assert!((0.0..=2.0).contains(&p.x));
+    }
+
+    #[test]
+    fn crossing_segments_meet_at_the_crossing() {
+        let (p, q) = closest_points_on_segments(
+            Vec2::new(-1.0, 0.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(0.0, -1.0),
+            Vec2::new(0.0, 1.0),
+        );
+        assert_near(p, Vec2::ZERO);
+        assert_near(q, Vec2::ZERO);
+    }
+
+    #[test]
+    fn apart_segments_meet_at_their_ends() {
+        let (p, q) = closest_points_on_segments(
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(2.0, 1.0),
+            Vec2::new(3.0, 2.0),
+        );
+        assert_near(p, Vec2::new(1.0, 0.0));
+        assert_near(q, Vec2::new(2.0, 1.0));
+    }
+
+    #[test]
+    fn segment_is_clipped_to_the_box() {
+        let half = Vec2::ONE;
+        let (t0, t1) = clip_segment_to_box(Vec2::new(-2.0, 0.0), Vec2::new(2.0, 0.0), half).unwrap();
+        assert!((t0 - 0.25).abs() < 1e-5 && (t1 - 0.75).abs() < 1e-5);
+
+        let inside = clip_segment_to_box(Vec2::new(-0.5, 0.0), Vec2::new(0.5, 0.0), half);
+        assert_eq!(inside, Some((0.0, 1.0)));
+
+        assert!(clip_segment_to_box(Vec2::new(-2.0, 2.0), Vec2::new(2.0, 2.0), half).is_none());
+        assert!(clip_segment_to_box(Vec2::new(2.5, 0.0), Vec2::new(0.0, 2.5), half).is_none());
+    }
+
+    #[test]
+    fn capsule_inside_box_leaves_through_the_nearest_face() {
+        let push = capsule_out_of_rect(
+            Vec2::new(-0.5, 0.2),
+            Vec2::new(0.5, 0.2),
+            0.1,
+            Vec2::ZERO,
+            AXES,
+            Vec2::new(2.0, 1.0),
+        );
+        // The top face is 0.8 from the core, plus the radius
+        assert_near(push.unwrap(), Vec2::new(0.0, 0.9));
+    }
+
+    #[test]
+    fn capsule_grazing_box_is_pushed_by_its_overlap() {
+        let half = Vec2::new(2.0, 1.0);
+        let push = capsule_out_of_rect(
+            Vec2::new(-0.5, 1.05),
+            Vec2::new(0.5, 1.05),
+            0.1,
+            Vec2::ZERO,
+            AXES,
+            half,
+        );
+        assert_near(push.unwrap(), Vec2::new(0.0, 0.05));
+
+        let clear =
+            capsule_out_of_rect(Vec2::new(-0.5, 1.2), Vec2::new(0.5, 1.2), 0.1, Vec2::ZERO, AXES, half);
+        assert!(clear.is_none());
+    }
+
+    #[test]
+    fn rotated_box_pushes_along_its_face_normal() {
+        let normal = DIAGONAL[0];
+        let outside = capsule_out_of_rect(normal * 1.1, normal * 1.1, 0.2, Vec2::ZERO, DIAGONAL, Vec2::ONE);
+        assert_near(outside.unwrap(), normal * 0.1);
+
+        let inside = capsule_out_of_rect(normal * 0.9, normal * 0.9, 0.2, Vec2::ZERO, DIAGONAL, Vec2::ONE);
+        assert_near(inside.unwrap(), normal * 0.3);
+    }
+
+    #[test]
+    fn overlapping_rects_separate_along_the_shallowest_axis() {
+        let push = rect_out_of_rect(Vec2::new(1.5, 0.0), AXES, Vec2::ONE, Vec2::ZERO, AXES, Vec2::ONE);
+        assert_near(push.unwrap(), Vec2::new(0.5, 0.0));
+
+        let apart = rect_out_of_rect(Vec2::new(2.5, 0.0), AXES, Vec2::ONE, Vec2::ZERO, AXES, Vec2::ONE);
+        assert!(apart.is_none());
+    }
+
+    #[test]
+    fn rotated_rect_separates_by_its_projected_extent() {
+        // The diamond reaches √2 along X, so it overlaps the square by √2 + 1 - 2.2
+        let push =
+            rect_out_of_rect(Vec2::new(2.2, 0.0), DIAGONAL, Vec2::ONE, Vec2::ZERO, AXES, Vec2::ONE);
+        assert_near(push.unwrap(), Vec2::new(std::f32::consts::SQRT_2 - 1.2, 0.0));
+    }
+
+    #[test]
+    fn every_pairing_pushes_the_first_shape_out() {
+        let ball = circle(Vec2::new(0.5, 0.0), 0.4);
+        assert_near(ball.penetration(&circle(Vec2::ZERO, 0.4)).unwrap(), Vec2::new(0.3, 0.0));
+
+        let block = Footprint::Rect { center: Vec2::ZERO, axes: AXES, half: Vec2::ONE };
+        let pebble = circle(Vec2::new(1.1, 0.0), 0.2);
+        assert_near(pebble.penetration(&block).unwrap(), Vec2::new(0.1, 0.0));
+        assert_near(block.penetration(&pebble).unwrap(), Vec2::new(-0.1, 0.0));
+
+        let shifted = Footprint::Rect { center: Vec2::new(0.0, 1.5), axes: AXES, half: Vec2::ONE };
+        assert_near(shifted.penetration(&block).unwrap(), Vec2::new(0.0, 0.5));
+
+        // Crossing cores are pushed apart by both radii
+        let across = Footprint::Capsule { a: Vec2::new(-1.0, 0.0), b: Vec2::new(1.0, 0.0), radius: 0.2 };
+        let down = Footprint::Capsule { a: Vec2::new(0.0, -1.0), b: Vec2::new(0.0, 1.0), radius: 0.2 };
+        assert!((across.penetration(&down).unwrap().length() - 0.4).abs() < 1e-5);
+    }
+
+    #[test]
+    fn rotated_collider_box_has_a_diamond_footprint() {
+        let collider = Collider {
+            shape: ColliderShape::Box { half_extents: Vec3::new(1.0, 0.5, 1.0) },
+            elevation: 0.5,
+        };
+        let placed = collider.place(Vec3::ZERO, Quat::from_rotation_y(FRAC_PI_4));
+        assert!(placed.bottom.abs() < 1e-5 && (placed.top - 1.0).abs() < 1e-5);
+        let Footprint::Rect { axes, .. } = placed.footprint else { panic!("expected a rect") };
+        assert!(axes[0].dot(DIAGONAL[0]).abs() > 0.999 || axes[0].dot(DIAGONAL[1]).abs() > 0.999);
+    }
+
+    #[test]
+    fn colliders_at_different_heights_do_not_touch() {
+        let cat = Collider {
+            shape: ColliderShape::Capsule { half_length: 0.22, radius: 0.18, half_height: 0.2 },
+            elevation: 0.2,
+        };
+        let lintel = Collider {
+            shape: ColliderShape::Box { half_extents: Vec3::new(1.0, 0.2, 0.3) },
+            elevation: 2.0,
+        };
+        let cat = cat.place(Vec3::ZERO, Quat::IDENTITY);
+        assert!(cat.penetration(&lintel.place(Vec3::ZERO, Quat::IDENTITY)).is_none());
+        assert!(cat.penetration(&lintel.place(Vec3::NEG_Y * 1.8, Quat::IDENTITY)).is_some());
+    }
+
+    #[test]
+    fn body_bounces_off_a_box_with_restitution() {
+        let dt = 1.0 / 64.0;
+        let mut app = App::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_secs_f32(dt));
+        let wide = Boundary::Circle { center: Vec2::ZERO, radius: 20.0 };
+        app.insert_resource(time)
+            .insert_resource(AreaBounds { play: wide.clone(), camera: wide })
+            .init_resource::<Heightfield>()
+            .init_resource::<StaticColliderGrid>();
+
+        app.world_mut().spawn((
+            Transform::from_xyz(1.0, 0.0, 0.0),
+            Collider { shape: ColliderShape::Box { half_extents: Vec3::splat(0.5) }, elevation: 0.5 },
+        ));
+        let incoming = 4.0;
+        let ball = app
+            .world_mut()
+            .spawn((
+                Transform::from_xyz(0.3, 0.0, 0.0),
+                PhysicsTranslation(Vec3::new(0.3, 0.0, 0.0)),
+                BodyVelocity(Vec2::new(incoming, 0.0)),
+                Collider { shape: ColliderShape::Circle { radius: 0.25, half_height: 0.25 }, elevation: 0.25 },
+            ))
+            .id();
+
+        app.world_mut().run_system_once(rebuild_static_grid).unwrap();
+        app.world_mut().run_system_once(step_bodies).unwrap();
+
+        // Friction slows the ball for one step before it reaches the box face
+        let normal_speed = incoming - ROLLING_FRICTION * dt;
+        let velocity = app.world().get::<BodyVelocity>(ball).unwrap().0;
+        assert_near(velocity, Vec2::new(-RESTITUTION * normal_speed, 0.0));
+        let position = app.world().get::<PhysicsTranslation>(ball).unwrap().0;
+        assert!(position.x <= 0.25 + 1e-4, "ball left inside the box at {position}");
+    }
+}
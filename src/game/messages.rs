@@ -0,0 +1,64 @@
+use bevy::prelude::*;
+
+use crate::game::area_def::Surface;
+
+// ─── Messages ────────────────────────────────────────────────────────────────
+
+/// An interaction that changed something: a push, a tip, a light or a pickup.
+#[derive(Message, Clone, Copy, Debug)]
+pub struct Interacted {
+    pub target: Entity,
+}
+
+#[derive(Message, Clone, Copy, Debug)]
+pub struct EmberLit {
+    pub target: Entity,
+}
+
+#[derive(Message, Clone, Copy, Debug)]
+pub struct CrystalActivated {
+    pub target: Entity,
+}
+
+/// One of the area's objectives has just been met.
+#[derive(Message, Clone, Copy, Debug)]
+pub struct ObjectiveComplete {
+    pub objective: Entity,
+}
+
+/// The cat touched down after being airborne.
+#[derive(Message, Clone, Copy, Debug)]
+pub struct Landed {
+    pub cat: Entity,
+    /// Downward speed at touchdown, in metres per second.
+    pub impact: f32,
+    pub surface: Surface,
+}
+
+#[derive(Message, Clone, Copy, Debug)]
+pub struct Jumped {
+    pub cat: Entity,
+}
+
+/// A pair of the cat's paws struck the ground, in time with its gait.
+#[derive(Message, Clone, Copy, Debug)]
+pub struct Footfall {
+    pub cat: Entity,
+    pub surface: Surface,
+}
+
+// ─── Plugin ──────────────────────────────────────────────────────────────────
+
+pub struct GameMessagesPlugin;
+
+impl Plugin for GameMessagesPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<Interacted>()
+            .add_message::<EmberLit>()
+            .add_message::<CrystalActivated>()
+            .add_message::<ObjectiveComplete>()
+            .add_message::<Landed>()
+            .add_message::<Jumped>()
+            .add_message::<Footfall>();
+    }
+}
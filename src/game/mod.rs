@@ -1,8 +1,20 @@
 pub mod area;
+pub mod area_def;
+pub mod boundary;
 pub mod camera;
-pub mod cave;
-pub mod crystal_cavern;
+pub mod collision;
 pub mod interactables;
-pub use cave::CavePlugin;
-pub use crystal_cavern::CrystalCavernPlugin;
+pub mod messages;
+pub mod objectives;
+pub mod physics;
+pub mod portal;
+pub mod progress;
+pub mod terrain;
+pub use area::AreaPlugin;
+pub use collision::CollisionPlugin;
 pub use interactables::InteractablesPlugin;
+pub use messages::GameMessagesPlugin;
+pub use objectives::ObjectivesPlugin;
+pub use physics::PhysicsPlugin;
+pub use portal::PortalPlugin;
+pub use progress::ProgressPlugin;
@@ -0,0 +1,195 @@
+use bevy::{ecs::system::SystemParam, prelude::*};
+
+use crate::{
+    game::{
+        area::{AreaObject, GameEntity, SelectedArea, selected_def},
+        area_def::{AreaRegistry, GoalDef, rgb},
+        interactables::{InteractionSystems, Lightable, Pushable, Tippable},
+        messages::ObjectiveComplete,
+    },
+    state::State,
+};
+
+// ─── Components ──────────────────────────────────────────────────────────────
+
+/// One of the selected area's goals. Progress and total are recounted from the
+/// spawned objects every frame, so restored saves start from the right numbers.
+#[derive(Component)]
+pub struct Objective {
+    pub label: String,
+    goal: GoalDef,
+    pub progress: u32,
+    pub total: u32,
+}
+
+impl Objective {
+    pub fn is_complete(&self) -> bool {
+        self.progress >= self.total
+    }
+}
+
+/// HUD line showing the progress of the objective entity it points at.
+#[derive(Component)]
+struct ObjectiveText(Entity);
+
+/// Every objective in the current area.
+#[derive(SystemParam)]
+pub struct Objectives<'w, 's> {
+    objectives: Query<'w, 's, Ref<'static, Objective>>,
+}
+
+impl Objectives<'_, '_> {
+    /// True once the area has objectives and every one of them is met.
+    pub fn all_complete(&self) -> bool {
+        !self.objectives.is_empty() && self.objectives.iter().all(|o| o.is_complete())
+    }
+
+    /// Share of the progress across every objective made so far, 0..1; 0 when
+    /// the area has no objectives.
+    pub fn share(&self) -> f32 {
+        let (progress, total) = self
+            .objectives
+            .iter()
+            .fold((0, 0), |(p, t), o| (p + o.progress.min(o.total), t + o.total));
+        if total == 0 { 0.0 } else { progress as f32 / total as f32 }
+    }
+
+    /// True when some objective's progress moved since the calling system last ran.
+    /// The first count after spawning doesn't count, so resuming never looks like progress.
+    /// Callers must run after `update_objectives`.
+    pub fn advanced(&self) -> bool {
+        self.objectives.iter().any(|o| o.is_changed() && !o.is_added())
+    }
+}
+
+// ─── Setup ───────────────────────────────────────────────────────────────────
+
+/// Spawns the selected area's objectives with their HUD lines.
+pub fn setup_objectives(
+    existing: Query<(), With<Objective>>,
+    selected: Res<SelectedArea>,
+    registry: Res<AreaRegistry>,
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+) {
+    if !existing.is_empty() {
+        return; // already spawned (guard against re-entry on resume)
+    }
+    let Some(def) = selected_def(&registry, &selected) else { return };
+    let objectives: Vec<Entity> = def
+        .objectives
+        .iter()
+        .map(|o| {
+            let objective =
+                Objective { label: o.label.clone(), goal: o.goal.clone(), progress: 0, total: 0 };
+            commands.spawn((GameEntity, objective)).id()
+        })
+        .collect();
+    // Areas without objectives, like the hub, have no progress HUD
+    let Some(hud) = def.hud.as_ref().filter(|_| !objectives.is_empty()) else { return };
+
+    let body_font = asset_server.load("fonts/Nunito-Regular.ttf");
+
+    // Progress HUD — top-right corner, one line per objective
+    let hud_container = commands.spawn((
+        GameEntity,
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            position_type: PositionType::Absolute,
+            flex_direction: FlexDirection::Column,
+            justify_content: JustifyContent::Start,
+            align_items: AlignItems::End,
+            padding: UiRect::axes(Val::Px(24.0), Val::Px(20.0)),
+            ..default()
+        },
+    )).id();
+    for objective in objectives {
+        commands.entity(hud_container).with_child((
+            ObjectiveText(objective),
+            Text::default(),
+            TextFont { font: body_font.clone(), font_size: 18.0, ..default() },
+            TextColor(rgb(hud.color)),
+        ));
+    }
+}
+
+// ─── Systems ─────────────────────────────────────────────────────────────────
+
+/// Recounts every objective from the objects in the area, announcing the ones just met.
+pub fn update_objectives(
+    mut objectives: Query<(Entity, &mut Objective)>,
+    mut completed: MessageWriter<ObjectiveComplete>,
+    lightables: Query<&Lightable>,
+    tippables: Query<&Tippable>,
+    pushables: Query<&Transform, With<Pushable>>,
+    named: Query<(&Name, &Transform), With<AreaObject>>,
+) {
+    for (entity, mut objective) in &mut objectives {
+        let (progress, total) = match &objective.goal {
+            GoalDef::Light { kind, count } => {
+                let spawned = lightables.iter().filter(|l| l.kind == *kind).count() as u32;
+                let lit = lightables.iter().filter(|l| l.kind == *kind && l.lit).count() as u32;
+                (lit, count.unwrap_or(spawned))
+            }
+            GoalDef::Tip { count } => {
+                let tipped = tippables.iter().filter(|t| t.tipped).count() as u32;
+                (tipped, count.unwrap_or(tippables.iter().count() as u32))
+            }
+            GoalDef::PushOnto { target, radius } => {
+                let on_target = named
+                    .iter()
+                    .find(|(name, _)| name.as_str() == target)
+                    .is_some_and(|(_, spot)| {
+                        pushables
+                            .iter()
+                            .any(|t| t.translation.xz().distance(spot.translation.xz()) < *radius)
+                    });
+                (on_target as u32, 1)
+            }
+        };
+        // Only touch the component when something moved, so change detection means progress
+        if objective.progress != progress || objective.total != total {
+            // The first count after spawning restores a save rather than completing anything
+            let newly_complete = !objective.is_added() && !objective.is_complete();
+            objective.progress = progress;
+            objective.total = total;
+            if newly_complete && objective.is_complete() {
+                completed.write(ObjectiveComplete { objective: entity });
+            }
+        }
+    }
+}
+
+/// Refreshes the HUD lines whenever an objective moves.
+fn update_objective_hud(
+    changed: Query<(), Changed<Objective>>,
+    new_lines: Query<(), Added<ObjectiveText>>,
+    objectives: Query<&Objective>,
+    mut lines: Query<(&ObjectiveText, &mut Text)>,
+) {
+    if changed.is_empty() && new_lines.is_empty() { return; }
+    for (line, mut text) in &mut lines {
+        if let Ok(o) = objectives.get(line.0) {
+            **text = format!("{}: {}/{}", o.label, o.progress.min(o.total), o.total);
+        }
+    }
+}
+
+// ─── Plugin ──────────────────────────────────────────────────────────────────
+
+pub struct ObjectivesPlugin;
+
+impl Plugin for ObjectivesPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(State::Playing), setup_objectives)
+            .add_systems(
+                Update,
+                (
+                    update_objectives.after(InteractionSystems::Behaviours),
+                    update_objective_hud.after(update_objectives),
+                )
+                    .run_if(in_state(State::Playing)),
+            );
+    }
+}
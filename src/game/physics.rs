@@ -0,0 +1,84 @@
+use bevy::prelude::*;
+
+use crate::state::State;
+
+/// Simulation rate for everything in `FixedUpdate`.
+const PHYSICS_HZ: f64 = 60.0;
+
+// ─── Components ──────────────────────────────────────────────────────────────
+
+/// Authoritative position of a simulated body, advanced only in `FixedUpdate`.
+/// The rendered `Transform` is interpolated from this each frame.
+#[derive(Component, Default, Clone, Copy)]
+#[require(PreviousPhysicsTranslation)]
+pub struct PhysicsTranslation(pub Vec3);
+
+/// XZ velocity of a simulated pushable body, in metres per second.
+#[derive(Component, Default, Clone, Copy)]
+pub struct BodyVelocity(pub Vec2);
+
+/// `PhysicsTranslation` at the start of the latest fixed step.
+#[derive(Component, Default, Clone, Copy)]
+pub struct PreviousPhysicsTranslation(pub Vec3);
+
+impl PhysicsTranslation {
+    /// Moves the body without interpolating across the jump.
+    pub fn teleport(&mut self, previous: &mut PreviousPhysicsTranslation, to: Vec3) {
+        self.0 = to;
+        previous.0 = to;
+    }
+}
+
+// ─── Systems ─────────────────────────────────────────────────────────────────
+
+/// Seeds newly spawned bodies from wherever their `Transform` put them.
+fn init_new_bodies(
+    mut bodies: Query<
+        (&Transform, &mut PhysicsTranslation, &mut PreviousPhysicsTranslation),
+        Added<PhysicsTranslation>,
+    >,
+) {
+    for (transform, mut current, mut previous) in &mut bodies {
+        current.teleport(&mut previous, transform.translation);
+    }
+}
+
+fn store_previous_translations(
+    mut bodies: Query<(&PhysicsTranslation, &mut PreviousPhysicsTranslation)>,
+) {
+    for (current, mut previous) in &mut bodies {
+        previous.0 = current.0;
+    }
+}
+
+/// Blends between the last two fixed steps so motion stays smooth at any frame rate.
+fn interpolate_transforms(
+    fixed_time: Res<Time<Fixed>>,
+    mut bodies: Query<(&mut Transform, &PhysicsTranslation, &PreviousPhysicsTranslation)>,
+) {
+    let alpha = fixed_time.overstep_fraction();
+    for (mut transform, current, previous) in &mut bodies {
+        transform.translation = previous.0.lerp(current.0, alpha);
+    }
+}
+
+pub struct PhysicsPlugin;
+
+impl Plugin for PhysicsPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Time::<Fixed>::from_hz(PHYSICS_HZ))
+            .add_systems(
+                RunFixedMainLoop,
+                (
+                    init_new_bodies.in_set(RunFixedMainLoopSystems::BeforeFixedMainLoop),
+                    interpolate_transforms
+                        .in_set(RunFixedMainLoopSystems::AfterFixedMainLoop)
+                        .run_if(in_state(State::Playing)),
+                ),
+            )
+            .add_systems(
+                FixedFirst,
+                store_previous_translations.run_if(in_state(State::Playing)),
+            );
+    }
+}
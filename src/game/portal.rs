@@ -0,0 +1,210 @@
+use bevy::prelude::*;
+
+use crate::{
+    audio::AmbientBed,
+    characters::Cat,
+    game::{
+        area::{GameEntity, SelectedArea},
+        area_def::{AreaRegistry, PortalDef},
+        camera::CameraMarkerComponent,
+        physics::{PhysicsTranslation, PreviousPhysicsTranslation},
+        terrain::{Heightfield, apply_area_terrain},
+    },
+    save::ActiveSave,
+    state::State,
+};
+
+/// Seconds the screen takes to fade to black, and the same again to fade back in.
+const FADE_SECONDS: f32 = 0.35;
+
+// ─── Components ──────────────────────────────────────────────────────────────
+
+/// Sends the cat to another area when it steps within `radius`.
+#[derive(Component)]
+pub struct Portal {
+    pub to: String,
+    pub radius: f32,
+    /// Where the cat comes out in the destination area.
+    pub arrive: Vec2,
+    /// Set once the cat has been outside the portal, so arriving or
+    /// resuming on top of one doesn't bounce the cat straight back.
+    armed: bool,
+}
+
+impl Portal {
+    pub fn new(def: &PortalDef) -> Self {
+        Self {
+            to: def.to.clone(),
+            radius: def.radius,
+            arrive: Vec2::new(def.arrive.0, def.arrive.1),
+            armed: false,
+        }
+    }
+}
+
+/// Full-screen overlay that drives a trip between areas. It outlives the swap,
+/// so the screen stays black while the old area unloads and the new one spawns.
+#[derive(Component)]
+struct Travel {
+    to: String,
+    /// Destination XZ, including the cat's offset from the portal it stepped into.
+    arrive: Vec2,
+    phase: TravelPhase,
+    alpha: f32,
+}
+
+#[derive(PartialEq, Eq, Clone, Copy)]
+enum TravelPhase {
+    FadeOut,
+    /// Old area unloaded; waiting for the new one to place the cat.
+    Arrive,
+    FadeIn,
+}
+
+// ─── Systems ─────────────────────────────────────────────────────────────────
+
+/// Starts a trip when the cat walks into an armed portal to an unlocked area.
+fn enter_portals(
+    mut commands: Commands,
+    registry: Res<AreaRegistry>,
+    save: Res<ActiveSave>,
+    travelling: Query<(), With<Travel>>,
+    cats: Query<&Transform, With<Cat>>,
+    mut portals: Query<(&mut Portal, &Transform)>,
+) {
+    let Ok(cat) = cats.single() else { return };
+    if !travelling.is_empty() {
+        return;
+    }
+    let cat_xz = cat.translation.xz();
+    for (mut portal, transform) in &mut portals {
+        let offset = cat_xz - transform.translation.xz();
+        let inside = offset.length() < portal.radius;
+        if !portal.armed {
+            portal.armed = !inside;
+            continue;
+        }
+        if !inside {
+            continue;
+        }
+        let Some(destination) = registry.get(&portal.to) else {
+            warn!("Portal leads to unknown area {:?}", portal.to);
+            continue;
+        };
+        if !save.is_unlocked(destination) {
+            continue;
+        }
+        commands.spawn((
+            GameEntity,
+            Travel {
+                to: portal.to.clone(),
+                arrive: portal.arrive + offset,
+                phase: TravelPhase::FadeOut,
+                alpha: 0.0,
+            },
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                position_type: PositionType::Absolute,
+                ..default()
+            },
+            BackgroundColor(Color::BLACK.with_alpha(0.0)),
+            GlobalZIndex(i32::MAX),
+        ));
+        return;
+    }
+}
+
+/// Fades the overlay out and back in, leaving play for the swap once the screen is black.
+fn fade_travel(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut next_state: ResMut<NextState<State>>,
+    mut overlays: Query<(Entity, &mut Travel, &mut BackgroundColor)>,
+) {
+    let step = time.delta_secs() / FADE_SECONDS;
+    for (entity, mut travel, mut background) in &mut overlays {
+        match travel.phase {
+            TravelPhase::FadeOut => {
+                travel.alpha = (travel.alpha + step).min(1.0);
+                if travel.alpha >= 1.0 {
+                    next_state.set(State::Travelling);
+                }
+            }
+            TravelPhase::Arrive => {}
+            TravelPhase::FadeIn => {
+                travel.alpha = (travel.alpha - step).max(0.0);
+                if travel.alpha <= 0.0 {
+                    commands.entity(entity).despawn();
+                }
+            }
+        }
+        background.0 = Color::BLACK.with_alpha(travel.alpha);
+    }
+}
+
+/// Unloads the area being left — everything but the cat, the camera, the
+/// overlay and the ambience, which crossfades — and selects the destination,
+/// then resumes play to spawn it.
+fn swap_area(
+    mut commands: Commands,
+    mut next_state: ResMut<NextState<State>>,
+    mut overlays: Query<&mut Travel>,
+    world: Query<
+        Entity,
+        (
+            With<GameEntity>,
+            Without<Cat>,
+            Without<CameraMarkerComponent>,
+            Without<Travel>,
+            Without<AmbientBed>,
+        ),
+    >,
+) {
+    let Ok(mut travel) = overlays.single_mut() else {
+        next_state.set(State::Playing);
+        return;
+    };
+    for entity in &world {
+        commands.entity(entity).despawn();
+    }
+    commands.insert_resource(SelectedArea(travel.to.clone()));
+    travel.phase = TravelPhase::Arrive;
+    next_state.set(State::Playing);
+}
+
+/// Moves the cat, facing and momentum intact, to its arrival point on the new floor.
+fn place_arrival(
+    terrain: Res<Heightfield>,
+    mut overlays: Query<&mut Travel>,
+    mut cats: Query<
+        (&mut PhysicsTranslation, &mut PreviousPhysicsTranslation, &mut Transform),
+        With<Cat>,
+    >,
+) {
+    let Ok(mut travel) = overlays.single_mut() else { return };
+    if travel.phase != TravelPhase::Arrive {
+        return;
+    }
+    if let Ok((mut position, mut previous, mut transform)) = cats.single_mut() {
+        let arrive = Vec3::new(travel.arrive.x, terrain.height_at(travel.arrive), travel.arrive.y);
+        position.teleport(&mut previous, arrive);
+        transform.translation = arrive;
+    }
+    travel.phase = TravelPhase::FadeIn;
+}
+
+// ─── Plugin ──────────────────────────────────────────────────────────────────
+
+pub struct PortalPlugin;
+
+impl Plugin for PortalPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(State::Travelling), swap_area)
+            .add_systems(OnEnter(State::Playing), place_arrival.after(apply_area_terrain))
+            .add_systems(
+                Update,
+                (enter_portals, fade_travel.after(enter_portals)).run_if(in_state(State::Playing)),
+            );
+    }
+}
@@ -0,0 +1,110 @@
+use bevy::prelude::*;
+
+use crate::{
+    game::{
+        area::{AreaObjectId, SelectedArea},
+        interactables::{AppInteractionExt, InteractionEvent, InteractionSystems, Secret},
+        messages::Interacted,
+        objectives::{Objectives, update_objectives},
+    },
+    save::{ActiveSave, RunResult},
+    state::State,
+};
+
+/// The current run in the selected area — play time and interactions — carried
+/// in the save between sessions.
+#[derive(Resource, Default)]
+pub struct AreaRun {
+    pub elapsed: f32,
+    pub interactions: u32,
+}
+
+/// Picks the run up from the save. Leaving play always stores it, so this is
+/// right whether play resumes from pause, a portal or the menu.
+fn start_area_run(selected: Res<SelectedArea>, save: Res<ActiveSave>, mut run: ResMut<AreaRun>) {
+    let saved = save.area(&selected.0);
+    run.elapsed = saved.map(|a| a.elapsed).unwrap_or_default();
+    run.interactions = saved.map(|a| a.interactions).unwrap_or_default();
+}
+
+fn tick_area_clock(time: Res<Time>, mut run: ResMut<AreaRun>) {
+    run.elapsed += time.delta_secs();
+}
+
+/// Counts the interactions that changed something.
+fn count_interactions(mut interacted: MessageReader<Interacted>, mut run: ResMut<AreaRun>) {
+    run.interactions += interacted.read().count() as u32;
+}
+
+/// Once the objectives are done: marks the area completed, records the run and
+/// best time, and hands over to the completion screen.
+fn record_completion(
+    selected: Res<SelectedArea>,
+    run: Res<AreaRun>,
+    objectives: Objectives,
+    mut save: ResMut<ActiveSave>,
+    mut next_state: ResMut<NextState<State>>,
+) {
+    if !objectives.advanced() || !objectives.all_complete() {
+        return;
+    }
+    let area = save.area_mut(&selected.0);
+    area.completed = true;
+    area.best_time = Some(area.best_time.map_or(run.elapsed, |b| b.min(run.elapsed)));
+    area.results.push(RunResult {
+        time: run.elapsed,
+        interactions: run.interactions,
+        secrets: area.secrets.len(),
+    });
+    // Write now so an unlock survives even if the game is closed mid-celebration
+    save.save();
+    next_state.set(State::AreaComplete);
+}
+
+/// Picks up a secret and remembers it in the save.
+fn collect_secrets(
+    mut commands: Commands,
+    mut interactions: MessageReader<InteractionEvent>,
+    mut interacted: MessageWriter<Interacted>,
+    selected: Res<SelectedArea>,
+    mut save: ResMut<ActiveSave>,
+    secrets: Query<&AreaObjectId, With<Secret>>,
+) {
+    for event in interactions.read() {
+        let Ok(id) = secrets.get(event.target) else { continue };
+        let area = save.area_mut(&selected.0);
+        if !area.has_secret(id.0) {
+            area.secrets.push(id.0);
+        }
+        commands.entity(event.target).despawn();
+        interacted.write(Interacted { target: event.target });
+    }
+}
+
+/// Formats seconds as `m:ss` for the menus.
+pub fn format_time(seconds: f32) -> String {
+    let total = seconds.max(0.0) as u32;
+    format!("{}:{:02}", total / 60, total % 60)
+}
+
+pub struct ProgressPlugin;
+
+impl Plugin for ProgressPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AreaRun>()
+            .add_systems(OnEnter(State::Playing), start_area_run)
+            .add_systems(
+                Update,
+                (
+                    tick_area_clock,
+                    count_interactions.after(InteractionSystems::Behaviours),
+                    record_completion
+                        .after(tick_area_clock)
+                        .after(count_interactions)
+                        .after(update_objectives),
+                )
+                    .run_if(in_state(State::Playing)),
+            )
+            .add_interaction::<Secret>(collect_secrets);
+    }
+}
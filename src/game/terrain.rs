@@ -0,0 +1,256 @@
+use bevy::{
+    asset::RenderAssetUsages,
+    image::{CompressedImageFormats, ImageSampler, ImageType},
+    mesh::{Indices, PrimitiveTopology},
+    prelude::*,
+};
+
+use crate::game::{
+    area::{SelectedArea, selected_def},
+    area_def::{AreaRegistry, TerrainDef, TerrainSource},
+};
+
+/// Samples per side of the heightfield grid.
+const RESOLUTION: usize = 97;
+
+/// Octaves of value noise summed for seeded terrain.
+const NOISE_OCTAVES: u32 = 3;
+
+/// Floor height of the current area, sampled on a square grid centred on the origin.
+/// An empty grid is the flat floor at y = 0.
+#[derive(Resource, Default)]
+pub struct Heightfield {
+    /// Area the heights were built for, so resuming or returning keeps them.
+    area: Option<String>,
+    /// Half the side length of the sampled square.
+    half_size: f32,
+    heights: Vec<f32>,
+}
+
+impl Heightfield {
+    /// Builds the heightfield described by `def`, covering a floor of `radius`.
+    pub fn generate(def: &TerrainDef, radius: f32) -> Self {
+        let n = RESOLUTION;
+        let cell = 2.0 * radius / (n - 1) as f32;
+        // Each source yields heights in -1..=1
+        let unit: Vec<f32> = match &def.source {
+            TerrainSource::Seeded { seed, scale } => (0..n * n)
+                .map(|index| {
+                    let p = Vec2::new((index % n) as f32, (index / n) as f32) * cell - radius;
+                    fbm(p / scale.max(0.01), *seed)
+                })
+                .collect(),
+            TerrainSource::Heightmap(path) => match load_heightmap(path) {
+                Ok(image) => (0..n * n)
+                    .map(|index| {
+                        let uv = Vec2::new((index % n) as f32, (index / n) as f32) / (n - 1) as f32;
+                        let x = (uv.x * (image.width() - 1) as f32).round() as u32;
+                        let y = (uv.y * (image.height() - 1) as f32).round() as u32;
+                        let brightness = image.get_color_at(x, y).map(|c| c.to_srgba().red).unwrap_or(0.5);
+                        brightness * 2.0 - 1.0
+                    })
+                    .collect(),
+                Err(e) => {
+                    warn!("Heightmap {path}: {e}");
+                    return Self::default();
+                }
+            },
+        };
+        let heights = unit
+            .into_iter()
+            .map(|h| {
+                let h = h * def.amplitude;
+                match def.terrace {
+                    Some(step) if step > 0.0 => (h / step).round() * step,
+                    _ => h,
+                }
+            })
+            .collect();
+        Self { area: None, half_size: radius, heights }
+    }
+
+    fn cell(&self) -> f32 {
+        2.0 * self.half_size / (RESOLUTION - 1) as f32
+    }
+
+    fn sample(&self, i: usize, j: usize) -> f32 {
+        self.heights[j * RESOLUTION + i]
+    }
+
+    /// Floor height under `xz`, bilinearly interpolated. Outside the grid the edge is extended.
+    pub fn height_at(&self, xz: Vec2) -> f32 {
+        if self.heights.is_empty() {
+            return 0.0;
+        }
+        let max = (RESOLUTION - 1) as f32;
+        let grid = ((xz + self.half_size) / self.cell()).clamp(Vec2::ZERO, Vec2::splat(max - 1e-3));
+        let (i, j) = (grid.x as usize, grid.y as usize);
+        let f = grid - Vec2::new(i as f32, j as f32);
+        let near = self.sample(i, j).lerp(self.sample(i + 1, j), f.x);
+        let far = self.sample(i, j + 1).lerp(self.sample(i + 1, j + 1), f.x);
+        near.lerp(far, f.y)
+    }
+
+    /// Rise per metre along X and Z at `xz` — points uphill.
+    pub fn gradient_at(&self, xz: Vec2) -> Vec2 {
+        if self.heights.is_empty() {
+            return Vec2::ZERO;
+        }
+        let e = self.cell() * 0.5;
+        Vec2::new(
+            self.height_at(xz + Vec2::X * e) - self.height_at(xz - Vec2::X * e),
+            self.height_at(xz + Vec2::Y * e) - self.height_at(xz - Vec2::Y * e),
+        ) / (2.0 * e)
+    }
+
+    /// Triangle mesh of the heightfield, trimmed to a disc of `radius`. An empty
+    /// heightfield, such as one whose heightmap failed to load, is the flat disc.
+    pub fn mesh(&self, radius: f32) -> Mesh {
+        if self.heights.is_empty() {
+            return Mesh::from(Circle::new(radius))
+                .rotated_by(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2));
+        }
+        let n = RESOLUTION;
+        let cell = self.cell();
+        let mut positions = Vec::with_capacity(n * n);
+        let mut normals = Vec::with_capacity(n * n);
+        let mut uvs = Vec::with_capacity(n * n);
+        for j in 0..n {
+            for i in 0..n {
+                let xz = Vec2::new(i as f32, j as f32) * cell - self.half_size;
+                let gradient = self.gradient_at(xz);
+                positions.push([xz.x, self.sample(i, j), xz.y]);
+                normals.push(Vec3::new(-gradient.x, 1.0, -gradient.y).normalize().to_array());
+                uvs.push([i as f32 / (n - 1) as f32, j as f32 / (n - 1) as f32]);
+            }
+        }
+
+        // Keep every quad touching the disc so the rim has no gaps
+        let keep = radius + cell;
+        let mut indices = Vec::new();
+        for j in 0..n - 1 {
+            for i in 0..n - 1 {
+                let center = (Vec2::new(i as f32, j as f32) + 0.5) * cell - self.half_size;
+                if center.length() > keep {
+                    continue;
+                }
+                let a = (j * n + i) as u32;
+                let b = a + 1;
+                let c = a + n as u32;
+                let d = c + 1;
+                indices.extend_from_slice(&[a, c, b, b, c, d]);
+            }
+        }
+
+        Mesh::new(PrimitiveTopology::TriangleList, RenderAssetUsages::default())
+            .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+            .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, normals)
+            .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, uvs)
+            .with_inserted_indices(Indices::U32(indices))
+    }
+}
+
+/// Reads a grayscale heightmap from `assets/`, like the area files, so the
+/// heightfield is ready before anything is spawned on it.
+fn load_heightmap(path: &str) -> Result<Image, String> {
+    let bytes = std::fs::read(format!("assets/{path}")).map_err(|e| e.to_string())?;
+    let extension = path.rsplit('.').next().unwrap_or("png");
+    Image::from_buffer(
+        &bytes,
+        ImageType::Extension(extension),
+        CompressedImageFormats::NONE,
+        false,
+        ImageSampler::Default,
+        RenderAssetUsages::default(),
+    )
+    .map_err(|e| e.to_string())
+}
+
+// ─── Noise ───────────────────────────────────────────────────────────────────
+
+/// Pseudo-random value in -1..=1 for a lattice point.
+fn lattice(x: i32, z: i32, seed: u32) -> f32 {
+    let mut h = (x as u32).wrapping_mul(0x8da6_b343)
+        ^ (z as u32).wrapping_mul(0xd816_3841)
+        ^ seed.wrapping_mul(0xcb1a_b31f);
+    h = (h ^ (h >> 13)).wrapping_mul(0x5bd1_e995);
+    h ^= h >> 15;
+    h as f32 / u32::MAX as f32 * 2.0 - 1.0
+}
+
+fn value_noise(p: Vec2, seed: u32) -> f32 {
+    let cell = p.floor();
+    let f = p - cell;
+    let u = f * f * (3.0 - 2.0 * f);
+    let (x, z) = (cell.x as i32, cell.y as i32);
+    let near = lattice(x, z, seed).lerp(lattice(x + 1, z, seed), u.x);
+    let far = lattice(x, z + 1, seed).lerp(lattice(x + 1, z + 1, seed), u.x);
+    near.lerp(far, u.y)
+}
+
+/// Value noise summed over octaves, normalised back to -1..=1.
+fn fbm(p: Vec2, seed: u32) -> f32 {
+    let mut total = 0.0;
+    let mut weight = 1.0;
+    let mut norm = 0.0;
+    for octave in 0..NOISE_OCTAVES {
+        total += value_noise(p * (1 << octave) as f32, seed.wrapping_add(octave)) * weight;
+        norm += weight;
+        weight *= 0.5;
+    }
+    total / norm
+}
+
+// ─── Systems ─────────────────────────────────────────────────────────────────
+
+/// Builds the selected area's `Heightfield` unless it is already built. Must run
+/// before anything is placed on the floor.
+pub fn apply_area_terrain(
+    selected: Res<SelectedArea>,
+    registry: Res<AreaRegistry>,
+    mut heightfield: ResMut<Heightfield>,
+) {
+    if heightfield.area.as_ref() == Some(&selected.0) {
+        return;
+    }
+    let Some(def) = selected_def(&registry, &selected) else { return };
+    *heightfield = match &def.floor.terrain {
+        Some(terrain) => Heightfield::generate(terrain, def.floor.radius),
+        None => Heightfield::default(),
+    };
+    heightfield.area = Some(selected.0.clone());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_heightmap_falls_back_to_a_flat_floor() {
+        let def = TerrainDef {
+            source: TerrainSource::Heightmap("terrain/missing.png".into()),
+            amplitude: 1.0,
+            terrace: None,
+        };
+        let heightfield = Heightfield::generate(&def, 4.0);
+        assert_eq!(heightfield.height_at(Vec2::new(1.0, -2.0)), 0.0);
+        assert_eq!(heightfield.gradient_at(Vec2::ZERO), Vec2::ZERO);
+
+        let mesh = heightfield.mesh(4.0);
+        assert!(mesh.count_vertices() > 0);
+        let positions = mesh.attribute(Mesh::ATTRIBUTE_POSITION).unwrap();
+        let flat = positions.as_float3().unwrap().iter().all(|p| p[1].abs() < 1e-5);
+        assert!(flat, "fallback floor should lie flat at y = 0");
+    }
+
+    #[test]
+    fn seeded_terrain_meshes_the_whole_grid() {
+        let def = TerrainDef {
+            source: TerrainSource::Seeded { seed: 7, scale: 4.0 },
+            amplitude: 0.45,
+            terrace: None,
+        };
+        let mesh = Heightfield::generate(&def, 14.0).mesh(14.0);
+        assert_eq!(mesh.count_vertices(), RESOLUTION * RESOLUTION);
+    }
+}
@@ -0,0 +1,366 @@
+use std::collections::HashMap;
+
+use bevy::{ecs::system::SystemParam, prelude::*};
+use serde::{Deserialize, Serialize};
+use strum::{EnumIter, EnumString, IntoEnumIterator, IntoStaticStr};
+
+use crate::settings::AppSettings;
+
+// ─── Actions ─────────────────────────────────────────────────────────────────
+
+/// Logical input actions. Systems ask for these instead of reading key codes.
+#[derive(
+    Clone, Copy, PartialEq, Eq, Hash, Debug, Serialize, Deserialize, EnumIter, IntoStaticStr,
+    EnumString,
+)]
+pub enum Action {
+    #[strum(to_string = "Move Forward")]
+    MoveForward,
+    #[strum(to_string = "Move Back")]
+    MoveBack,
+    #[strum(to_string = "Move Left")]
+    MoveLeft,
+    #[strum(to_string = "Move Right")]
+    MoveRight,
+    Jump,
+    Run,
+    Interact,
+    #[strum(to_string = "Cycle Coat")]
+    CycleCoat,
+    Pause,
+    #[strum(to_string = "Camera Left")]
+    CameraLeft,
+    #[strum(to_string = "Camera Right")]
+    CameraRight,
+    #[strum(to_string = "Camera Up")]
+    CameraUp,
+    #[strum(to_string = "Camera Down")]
+    CameraDown,
+    #[strum(to_string = "Menu Up")]
+    MenuUp,
+    #[strum(to_string = "Menu Down")]
+    MenuDown,
+    #[strum(to_string = "Menu Left")]
+    MenuLeft,
+    #[strum(to_string = "Menu Right")]
+    MenuRight,
+    #[strum(to_string = "Menu Confirm")]
+    MenuConfirm,
+    #[strum(to_string = "Menu Back")]
+    MenuBack,
+}
+
+/// Actions only conflict with other actions that are read at the same time.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum ActionContext {
+    Gameplay,
+    Menu,
+}
+
+impl Action {
+    fn context(self) -> ActionContext {
+        match self {
+            Action::MenuUp
+            | Action::MenuDown
+            | Action::MenuLeft
+            | Action::MenuRight
+            | Action::MenuConfirm
+            | Action::MenuBack => ActionContext::Menu,
+            _ => ActionContext::Gameplay,
+        }
+    }
+
+    fn default_keys(self) -> Vec<KeyCode> {
+        match self {
+            Action::MoveForward => vec![KeyCode::KeyW],
+            Action::MoveBack    => vec![KeyCode::KeyS],
+            Action::MoveLeft    => vec![KeyCode::KeyA],
+            Action::MoveRight   => vec![KeyCode::KeyD],
+            Action::Jump        => vec![KeyCode::Space],
+            Action::Run         => vec![KeyCode::ShiftLeft],
+            Action::Interact    => vec![KeyCode::KeyE],
+            Action::CycleCoat   => vec![KeyCode::Tab],
+            Action::Pause       => vec![KeyCode::Escape],
+            Action::CameraLeft  => vec![KeyCode::ArrowLeft],
+            Action::CameraRight => vec![KeyCode::ArrowRight],
+            Action::CameraUp    => vec![KeyCode::ArrowUp],
+            Action::CameraDown  => vec![KeyCode::ArrowDown],
+            Action::MenuUp      => vec![KeyCode::ArrowUp],
+            Action::MenuDown    => vec![KeyCode::ArrowDown],
+            Action::MenuLeft    => vec![KeyCode::ArrowLeft],
+            Action::MenuRight   => vec![KeyCode::ArrowRight],
+            Action::MenuConfirm => vec![KeyCode::Space, KeyCode::Enter],
+            Action::MenuBack    => vec![KeyCode::Escape],
+        }
+    }
+
+    /// Movement and camera come from the sticks, so only buttons are listed here.
+    fn default_buttons(self) -> Vec<GamepadButton> {
+        match self {
+            Action::Jump        => vec![GamepadButton::South],
+            Action::Run         => vec![GamepadButton::RightTrigger2],
+            Action::Interact    => vec![GamepadButton::West],
+            Action::CycleCoat   => vec![GamepadButton::North],
+            Action::Pause       => vec![GamepadButton::Start],
+            Action::MenuUp      => vec![GamepadButton::DPadUp],
+            Action::MenuDown    => vec![GamepadButton::DPadDown],
+            Action::MenuLeft    => vec![GamepadButton::DPadLeft],
+            Action::MenuRight   => vec![GamepadButton::DPadRight],
+            Action::MenuConfirm => vec![GamepadButton::South],
+            Action::MenuBack    => vec![GamepadButton::East],
+            _ => Vec::new(),
+        }
+    }
+}
+
+// ─── Bindings ────────────────────────────────────────────────────────────────
+
+/// Keys bound to each action — persisted in `AppSettings`.
+/// Actions missing from a saved file fall back to their defaults.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct InputBindings {
+    keys: HashMap<Action, Vec<KeyCode>>,
+    #[serde(default)]
+    buttons: HashMap<Action, Vec<GamepadButton>>,
+}
+
+impl InputBindings {
+    pub fn keys(&self, action: Action) -> Vec<KeyCode> {
+        self.keys.get(&action).cloned().unwrap_or_else(|| action.default_keys())
+    }
+
+    pub fn buttons(&self, action: Action) -> Vec<GamepadButton> {
+        self.buttons.get(&action).cloned().unwrap_or_else(|| action.default_buttons())
+    }
+
+    /// The other action in the same context already using `key`, if any.
+    pub fn conflict(&self, action: Action, key: KeyCode) -> Option<Action> {
+        Action::iter().find(|&other| {
+            other != action && other.context() == action.context() && self.keys(other).contains(&key)
+        })
+    }
+
+    /// Replaces the keys of `action` with `key`, refusing keys claimed by another action.
+    pub fn rebind(&mut self, action: Action, key: KeyCode) -> Result<(), Action> {
+        if let Some(other) = self.conflict(action, key) {
+            return Err(other);
+        }
+        self.keys.insert(action, vec![key]);
+        Ok(())
+    }
+}
+
+/// Human-readable key name for menus, e.g. `KeyW` → "W".
+pub fn key_label(key: KeyCode) -> String {
+    let raw = format!("{key:?}");
+    raw.strip_prefix("Key")
+        .or_else(|| raw.strip_prefix("Digit"))
+        .unwrap_or(&raw)
+        .to_string()
+}
+
+// ─── Runtime ─────────────────────────────────────────────────────────────────
+
+/// The live action → key mapping, mirrored from `AppSettings::bindings`.
+#[derive(Resource, Default)]
+pub struct InputActions {
+    bindings: InputBindings,
+}
+
+impl InputActions {
+    pub fn keys(&self, action: Action) -> Vec<KeyCode> {
+        self.bindings.keys(action)
+    }
+
+    pub fn buttons(&self, action: Action) -> Vec<GamepadButton> {
+        self.bindings.buttons(action)
+    }
+}
+
+/// Stick deflection below this is treated as centred.
+const STICK_DEADZONE: f32 = 0.15;
+
+fn apply_deadzone(stick: Vec2) -> Vec2 {
+    if stick.length() < STICK_DEADZONE { Vec2::ZERO } else { stick.clamp_length_max(1.0) }
+}
+
+/// Reads actions through the current bindings, from the keyboard and every
+/// connected `Gamepad` entity.
+#[derive(SystemParam)]
+pub struct Actions<'w, 's> {
+    keyboard: Res<'w, ButtonInput<KeyCode>>,
+    gamepads: Query<'w, 's, &'static Gamepad>,
+    actions: Res<'w, InputActions>,
+}
+
+impl Actions<'_, '_> {
+    pub fn pressed(&self, action: Action) -> bool {
+        self.keyboard.any_pressed(self.actions.keys(action))
+            || self.gamepads.iter().any(|g| g.any_pressed(self.actions.buttons(action)))
+    }
+
+    pub fn just_pressed(&self, action: Action) -> bool {
+        self.keyboard.any_just_pressed(self.actions.keys(action)) || self.gamepad_just_pressed(action)
+    }
+
+    /// The gamepad half of `just_pressed`, for widgets like sliders that
+    /// already read the keyboard themselves.
+    pub fn gamepad_just_pressed(&self, action: Action) -> bool {
+        self.gamepads.iter().any(|g| g.any_just_pressed(self.actions.buttons(action)))
+    }
+
+    /// -1, 0 or 1 depending on which of the two opposing actions is held.
+    pub fn axis(&self, negative: Action, positive: Action) -> f32 {
+        (self.pressed(positive) as i8 - self.pressed(negative) as i8) as f32
+    }
+
+    /// Movement input with length in 0..=1 — x is right, y is forward.
+    /// An analog left stick wins over the digital movement keys.
+    pub fn move_axis(&self) -> Vec2 {
+        let stick = self.gamepads.iter().map(|g| apply_deadzone(g.left_stick())).find(|s| *s != Vec2::ZERO);
+        stick.unwrap_or_else(|| {
+            Vec2::new(
+                self.axis(Action::MoveLeft, Action::MoveRight),
+                self.axis(Action::MoveBack, Action::MoveForward),
+            )
+            .normalize_or_zero()
+        })
+    }
+
+    /// Camera orbit input from the right stick — x is right, y is up.
+    pub fn camera_axis(&self) -> Vec2 {
+        self.gamepads
+            .iter()
+            .map(|g| apply_deadzone(g.right_stick()))
+            .find(|s| *s != Vec2::ZERO)
+            .unwrap_or(Vec2::ZERO)
+    }
+}
+
+fn sync_input_actions(settings: Res<AppSettings>, mut actions: ResMut<InputActions>) {
+    if settings.is_changed() && actions.bindings != settings.bindings {
+        actions.bindings = settings.bindings.clone();
+    }
+}
+
+pub struct InputActionsPlugin;
+
+impl Plugin for InputActionsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<InputActions>().add_systems(
+            PreUpdate,
+            sync_input_actions.run_if(resource_exists::<AppSettings>),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+
+    fn app_with(gamepad: Gamepad) -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<InputActions>();
+        app.world_mut().spawn(gamepad);
+        app
+    }
+
+    fn stick_pad(axes: &[(GamepadAxis, f32)]) -> Gamepad {
+        let mut gamepad = Gamepad::default();
+        for &(axis, value) in axes {
+            gamepad.analog_mut().set(axis, value);
+        }
+        gamepad
+    }
+
+    fn just_pressed(app: &mut App, action: Action) -> bool {
+        app.world_mut().run_system_once(move |actions: Actions| actions.just_pressed(action)).unwrap()
+    }
+
+    fn move_axis(actions: Actions) -> Vec2 {
+        actions.move_axis()
+    }
+
+    fn camera_axis(actions: Actions) -> Vec2 {
+        actions.camera_axis()
+    }
+
+    #[test]
+    fn buttons_trigger_their_actions() {
+        let mut gamepad = Gamepad::default();
+        gamepad.digital_mut().press(GamepadButton::South);
+        gamepad.digital_mut().press(GamepadButton::DPadLeft);
+        let mut app = app_with(gamepad);
+
+        assert!(just_pressed(&mut app, Action::Jump));
+        assert!(just_pressed(&mut app, Action::MenuConfirm));
+        assert!(just_pressed(&mut app, Action::MenuLeft));
+        assert!(!just_pressed(&mut app, Action::MenuRight));
+        assert!(!just_pressed(&mut app, Action::Interact));
+    }
+
+    #[test]
+    fn dpad_steers_menus_on_every_side() {
+        for (button, action) in [
+            (GamepadButton::DPadUp, Action::MenuUp),
+            (GamepadButton::DPadDown, Action::MenuDown),
+            (GamepadButton::DPadLeft, Action::MenuLeft),
+            (GamepadButton::DPadRight, Action::MenuRight),
+        ] {
+            let mut gamepad = Gamepad::default();
+            gamepad.digital_mut().press(button);
+            let mut app = app_with(gamepad);
+            assert!(just_pressed(&mut app, action), "{button:?} should trigger {action:?}");
+            let gamepad_only = app
+                .world_mut()
+                .run_system_once(move |actions: Actions| actions.gamepad_just_pressed(action))
+                .unwrap();
+            assert!(gamepad_only);
+        }
+    }
+
+    #[test]
+    fn small_stick_deflection_is_ignored() {
+        let mut app = app_with(stick_pad(&[
+            (GamepadAxis::LeftStickX, 0.1),
+            (GamepadAxis::LeftStickY, 0.05),
+        ]));
+        assert_eq!(app.world_mut().run_system_once(move_axis).unwrap(), Vec2::ZERO);
+    }
+
+    #[test]
+    fn stick_past_deadzone_passes_through() {
+        let mut app = app_with(stick_pad(&[(GamepadAxis::LeftStickX, 0.6)]));
+        let axis = app.world_mut().run_system_once(move_axis).unwrap();
+        assert!((axis - Vec2::new(0.6, 0.0)).length() < 1e-6);
+    }
+
+    #[test]
+    fn diagonal_stick_is_clamped_to_unit_length() {
+        let mut app = app_with(stick_pad(&[
+            (GamepadAxis::LeftStickX, 1.0),
+            (GamepadAxis::LeftStickY, 1.0),
+        ]));
+        let axis = app.world_mut().run_system_once(move_axis).unwrap();
+        assert!((axis.length() - 1.0).abs() < 1e-5);
+        assert!((axis.x - axis.y).abs() < 1e-6);
+    }
+
+    #[test]
+    fn right_stick_orbits_the_camera() {
+        let mut app = app_with(stick_pad(&[
+            (GamepadAxis::RightStickX, -0.5),
+            (GamepadAxis::RightStickY, 0.3),
+            (GamepadAxis::LeftStickX, 0.9),
+        ]));
+        let camera = app.world_mut().run_system_once(camera_axis).unwrap();
+        assert!((camera - Vec2::new(-0.5, 0.3)).length() < 1e-6);
+
+        let mut app = app_with(stick_pad(&[(GamepadAxis::RightStickX, 0.1)]));
+        assert_eq!(app.world_mut().run_system_once(camera_axis).unwrap(), Vec2::ZERO);
+    }
+}
@@ -6,27 +6,38 @@ use bevy::{
     state::state::{OnEnter, OnExit},
     window::{CursorGrabMode, CursorOptions, WindowMode, PrimaryWindow},
 };
-use game::area::{AreaBounds, GameEntity, apply_area_bounds, setup_area};
+use game::area::GameEntity;
 
 mod audio;
 mod characters;
 mod game;
+mod input;
 mod loading;
 mod render;
+mod save;
 mod settings;
 mod state;
 mod ui;
 
 use characters::{CatPlugin, setup_cat};
-use game::camera::{orbit_camera_keyboard, orbit_camera_mouse, setup_camera};
-use game::{CavePlugin, CrystalCavernPlugin, InteractablesPlugin};
-use game::area::SelectedArea;
+use game::camera::{
+    celebrate_camera, orbit_camera_keyboard, orbit_camera_mouse, setup_camera, start_celebration,
+};
+use game::{
+    AreaPlugin, CollisionPlugin, GameMessagesPlugin, InteractablesPlugin, ObjectivesPlugin,
+    PhysicsPlugin, PortalPlugin, ProgressPlugin,
+};
+use input::InputActionsPlugin;
 use loading::LoadingPlugin;
-use audio::AudioPlugin;
+use audio::{AudioPlugin, music::MusicPlugin};
 use render::{BlurPlugin, RockMaterialPlugin};
+use save::SavePlugin;
 use settings::SettingsPlugin;
 use state::State;
-use ui::{AreasMenuPlugin, MainMenuPlugin, OptionsPlugin, PausedPlugin, common::despawn_menu_camera};
+use ui::{
+    AreasMenuPlugin, ControlsPlugin, MainMenuPlugin, OptionsPlugin, PausedPlugin, ResultsPlugin,
+    common::despawn_menu_camera,
+};
 
 
 #[derive(Resource, Default)]
@@ -66,29 +77,37 @@ fn main() {
         .add_plugins((
             LoadingPlugin,
             SettingsPlugin,
+            InputActionsPlugin,
+            SavePlugin,
             CatPlugin,
             MainMenuPlugin,
             AreasMenuPlugin,
             OptionsPlugin,
+            ControlsPlugin,
             PausedPlugin,
             AudioPlugin,
             BlurPlugin,
             RockMaterialPlugin,
-            CavePlugin,
-            CrystalCavernPlugin,
+            AreaPlugin,
             InteractablesPlugin,
         ))
+        .add_plugins((
+            PhysicsPlugin,
+            CollisionPlugin,
+            PortalPlugin,
+            ProgressPlugin,
+            ObjectivesPlugin,
+            ResultsPlugin,
+            GameMessagesPlugin,
+            MusicPlugin,
+        ))
         .init_resource::<Game>()
-        .init_resource::<SelectedArea>()
-        .init_resource::<AreaBounds>()
         .init_state::<State>()
         .add_systems(OnEnter(State::MainMenu), cleanup_game_world)
         .add_systems(
             OnEnter(State::Playing),
             (
-                apply_area_bounds,
                 despawn_menu_camera,
-                setup_area.after(apply_area_bounds),
                 setup_camera.after(setup_cat),
                 set_cursor_hidden,
             ),
@@ -97,6 +116,8 @@ fn main() {
         .add_systems(
             Update,
             (orbit_camera_keyboard, orbit_camera_mouse).run_if(in_state(State::Playing)),
-        );
+        )
+        .add_systems(OnEnter(State::AreaComplete), start_celebration)
+        .add_systems(Update, celebrate_camera.run_if(in_state(State::AreaComplete)));
     app.run();
 }
@@ -0,0 +1,256 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    characters::{Cat, CatMode},
+    game::{
+        area::{AreaObjectId, SelectedArea},
+        area_def::AreaDef,
+        interactables::{Lightable, Pushable, Tippable},
+        progress::AreaRun,
+    },
+    settings::AppSettings,
+    state::State,
+};
+
+const SAVE_DIR: &str = "saves";
+
+/// Bumped whenever the save layout changes; older files are ignored.
+const SAVE_VERSION: u32 = 1;
+
+/// Number of save slots offered in the main menu, numbered from 1.
+pub const SAVE_SLOTS: u8 = 3;
+
+// ─── Save data ───────────────────────────────────────────────────────────────
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default)]
+pub struct SavedTransform {
+    pub translation: (f32, f32, f32),
+    pub rotation: (f32, f32, f32, f32),
+}
+
+impl From<&Transform> for SavedTransform {
+    fn from(t: &Transform) -> Self {
+        let r = t.rotation;
+        Self {
+            translation: (t.translation.x, t.translation.y, t.translation.z),
+            rotation: (r.x, r.y, r.z, r.w),
+        }
+    }
+}
+
+impl SavedTransform {
+    /// Applies the saved translation and rotation, keeping the current scale.
+    pub fn apply(&self, transform: &mut Transform) {
+        let (x, y, z) = self.translation;
+        let (rx, ry, rz, rw) = self.rotation;
+        transform.translation = Vec3::new(x, y, z);
+        transform.rotation = Quat::from_xyzw(rx, ry, rz, rw).normalize();
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+pub struct CatSave {
+    pub transform: SavedTransform,
+    pub mode: CatMode,
+}
+
+/// Outcome of one completed run through an area.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+pub struct RunResult {
+    /// Seconds of play from the start of the run to completion.
+    pub time: f32,
+    pub interactions: u32,
+    /// Secrets found in the area by the end of the run, counting earlier runs.
+    pub secrets: usize,
+}
+
+/// Per-area progress. Objects are identified by their index in `AreaDef::objects`.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct AreaSave {
+    pub lit: Vec<usize>,
+    pub tipped: Vec<usize>,
+    /// Last transform of every pushable and tippable object.
+    pub transforms: Vec<(usize, SavedTransform)>,
+    pub cat: Option<CatSave>,
+    /// Play time in the area so far, in seconds.
+    #[serde(default)]
+    pub elapsed: f32,
+    /// The area's objective has been completed at least once.
+    #[serde(default)]
+    pub completed: bool,
+    /// Fastest completion, in seconds of play.
+    #[serde(default)]
+    pub best_time: Option<f32>,
+    /// Indices of the secrets found.
+    #[serde(default)]
+    pub secrets: Vec<usize>,
+    /// Interactions made in the area so far.
+    #[serde(default)]
+    pub interactions: u32,
+    /// Every completed run, oldest first.
+    #[serde(default)]
+    pub results: Vec<RunResult>,
+}
+
+impl AreaSave {
+    pub fn is_lit(&self, index: usize) -> bool {
+        self.lit.contains(&index)
+    }
+
+    pub fn is_tipped(&self, index: usize) -> bool {
+        self.tipped.contains(&index)
+    }
+
+    pub fn transform(&self, index: usize) -> Option<&SavedTransform> {
+        self.transforms.iter().find(|(i, _)| *i == index).map(|(_, t)| t)
+    }
+
+    pub fn has_secret(&self, index: usize) -> bool {
+        self.secrets.contains(&index)
+    }
+
+    /// Starts the area over — objects, cat, clock and interactions — keeping
+    /// completion, records and the secrets already found.
+    pub fn restart(&mut self) {
+        *self = AreaSave {
+            completed: self.completed,
+            best_time: self.best_time,
+            secrets: std::mem::take(&mut self.secrets),
+            results: std::mem::take(&mut self.results),
+            ..default()
+        };
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SaveGame {
+    pub version: u32,
+    /// Keyed by area id.
+    pub areas: HashMap<String, AreaSave>,
+}
+
+impl Default for SaveGame {
+    fn default() -> Self {
+        Self { version: SAVE_VERSION, areas: HashMap::new() }
+    }
+}
+
+// ─── Active slot ─────────────────────────────────────────────────────────────
+
+/// The save game for the slot chosen in the main menu.
+/// `slot` is 0 until the first slot has been loaded.
+#[derive(Resource, Default)]
+pub struct ActiveSave {
+    pub slot: u8,
+    pub game: SaveGame,
+}
+
+impl ActiveSave {
+    fn path(slot: u8) -> String {
+        format!("{SAVE_DIR}/slot_{slot}.ron")
+    }
+
+    pub fn load(slot: u8) -> Self {
+        let game = std::fs::read_to_string(Self::path(slot))
+            .ok()
+            .and_then(|s| ron::from_str::<SaveGame>(&s).ok())
+            .filter(|g| {
+                let current = g.version == SAVE_VERSION;
+                if !current {
+                    warn!("Ignoring save slot {slot}: version {} != {SAVE_VERSION}", g.version);
+                }
+                current
+            })
+            .unwrap_or_default();
+        Self { slot, game }
+    }
+
+    pub fn save(&self) {
+        if std::fs::create_dir_all(SAVE_DIR).is_err() {
+            return;
+        }
+        if let Ok(s) = ron::ser::to_string_pretty(&self.game, ron::ser::PrettyConfig::default()) {
+            let _ = std::fs::write(Self::path(self.slot), s);
+        }
+    }
+
+    pub fn area(&self, id: &str) -> Option<&AreaSave> {
+        self.game.areas.get(id)
+    }
+
+    pub fn area_mut(&mut self, id: &str) -> &mut AreaSave {
+        self.game.areas.entry(id.to_string()).or_default()
+    }
+
+    /// Whether `def` is open to play — its required area, if any, has been completed.
+    pub fn is_unlocked(&self, def: &AreaDef) -> bool {
+        def.requires.as_ref().is_none_or(|id| self.area(id).is_some_and(|a| a.completed))
+    }
+}
+
+// ─── Systems ─────────────────────────────────────────────────────────────────
+
+/// Reloads the active save whenever the slot in `AppSettings` changes.
+fn sync_active_slot(settings: Res<AppSettings>, mut save: ResMut<ActiveSave>) {
+    if save.slot != settings.save_slot {
+        *save = ActiveSave::load(settings.save_slot);
+    }
+}
+
+/// Records the current area's state into the active save and writes it to disk.
+/// Runs whenever play stops, so pausing also saves. Progress recorded as it
+/// happens — completion, records and secrets — is carried over untouched.
+fn store_area_state(
+    selected: Res<SelectedArea>,
+    run: Res<AreaRun>,
+    mut save: ResMut<ActiveSave>,
+    objects: Query<(
+        &AreaObjectId,
+        &Transform,
+        Option<&Lightable>,
+        Option<&Tippable>,
+        Option<&Pushable>,
+    )>,
+    cats: Query<(&Transform, &Cat)>,
+) {
+    let previous = save.area(&selected.0).cloned().unwrap_or_default();
+    let mut area = AreaSave {
+        elapsed: run.elapsed,
+        interactions: run.interactions,
+        completed: previous.completed,
+        best_time: previous.best_time,
+        secrets: previous.secrets,
+        results: previous.results,
+        ..default()
+    };
+    for (id, transform, lightable, tippable, pushable) in &objects {
+        if lightable.is_some_and(|l| l.lit) {
+            area.lit.push(id.0);
+        }
+        if tippable.is_some_and(|t| t.tipped) {
+            area.tipped.push(id.0);
+        }
+        if tippable.is_some() || pushable.is_some() {
+            area.transforms.push((id.0, transform.into()));
+        }
+    }
+    area.cat = cats.single().ok().map(|(transform, cat)| CatSave {
+        transform: transform.into(),
+        mode: cat.mode(),
+    });
+    save.game.areas.insert(selected.0.clone(), area);
+    save.save();
+}
+
+pub struct SavePlugin;
+
+impl Plugin for SavePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ActiveSave>()
+            .add_systems(Update, sync_active_slot.run_if(resource_exists::<AppSettings>))
+            .add_systems(OnExit(State::Playing), store_area_state);
+    }
+}
@@ -1,31 +1,103 @@
 use bevy::{
-    audio::{GlobalVolume, Volume},
     prelude::*,
     window::{MonitorSelection, WindowMode},
 };
 use serde::{Deserialize, Serialize};
 
+use crate::input::InputBindings;
+
 const SETTINGS_PATH: &str = "settings.ron";
 
+/// Mixer bus a sound plays on. Every bus is scaled by `Master`.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub enum AudioBus {
+    Master,
+    Music,
+    Ambience,
+    #[default]
+    Sfx,
+    Ui,
+}
+
+/// Volume of every bus but master, each 0.0 (silent) to 1.0 (full).
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct BusVolumes {
+    pub music: f32,
+    pub ambience: f32,
+    pub sfx: f32,
+    pub ui: f32,
+}
+
+impl Default for BusVolumes {
+    fn default() -> Self {
+        Self { music: 0.8, ambience: 1.0, sfx: 1.0, ui: 0.7 }
+    }
+}
+
 #[derive(Resource, Serialize, Deserialize, Clone, Debug)]
 pub struct AppSettings {
     /// Master volume, 0.0 (silent) to 1.0 (full).
     pub volume: f32,
+    #[serde(default)]
+    pub buses: BusVolumes,
     pub fullscreen: bool,
     pub invert_mouse: bool,
+    /// Save slot chosen in the main menu, 1-based.
+    #[serde(default = "default_save_slot")]
+    pub save_slot: u8,
+    #[serde(default)]
+    pub bindings: InputBindings,
+}
+
+fn default_save_slot() -> u8 {
+    1
 }
 
 impl Default for AppSettings {
     fn default() -> Self {
         Self {
             volume: 0.5,
+            buses: BusVolumes::default(),
             fullscreen: true,
             invert_mouse: false,
+            save_slot: default_save_slot(),
+            bindings: InputBindings::default(),
         }
     }
 }
 
 impl AppSettings {
+    /// The slider value of `bus` on its own.
+    pub fn bus_volume(&self, bus: AudioBus) -> f32 {
+        match bus {
+            AudioBus::Master => self.volume,
+            AudioBus::Music => self.buses.music,
+            AudioBus::Ambience => self.buses.ambience,
+            AudioBus::Sfx => self.buses.sfx,
+            AudioBus::Ui => self.buses.ui,
+        }
+    }
+
+    pub fn set_bus_volume(&mut self, bus: AudioBus, volume: f32) {
+        let slot = match bus {
+            AudioBus::Master => &mut self.volume,
+            AudioBus::Music => &mut self.buses.music,
+            AudioBus::Ambience => &mut self.buses.ambience,
+            AudioBus::Sfx => &mut self.buses.sfx,
+            AudioBus::Ui => &mut self.buses.ui,
+        };
+        *slot = volume.clamp(0.0, 1.0);
+    }
+
+    /// What a sound on `bus` is scaled by: the bus and master together.
+    pub fn bus_gain(&self, bus: AudioBus) -> f32 {
+        match bus {
+            AudioBus::Master => self.volume,
+            other => self.volume * self.bus_volume(other),
+        }
+    }
+
     pub fn load() -> Self {
         std::fs::read_to_string(SETTINGS_PATH)
             .ok()
@@ -44,12 +116,6 @@ fn startup(mut commands: Commands) {
     commands.insert_resource(AppSettings::load());
 }
 
-fn apply_volume(settings: Res<AppSettings>, mut global_volume: ResMut<GlobalVolume>) {
-    if settings.is_changed() {
-        global_volume.volume = Volume::Linear(settings.volume);
-    }
-}
-
 fn apply_fullscreen(settings: Res<AppSettings>, mut windows: Query<&mut Window>) {
     if settings.is_changed() {
         for mut window in &mut windows {
@@ -72,6 +138,6 @@ pub struct SettingsPlugin;
 impl Plugin for SettingsPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(Startup, startup)
-            .add_systems(Update, (apply_volume, apply_fullscreen, save_on_change));
+            .add_systems(Update, (apply_fullscreen, save_on_change));
     }
 }
@@ -9,7 +9,12 @@ pub enum State {
     Loading,
     MainMenu,
     OptionsMenu,
+    ControlsMenu,
     Paused,
     Playing,
+    /// Screen is black while the cat moves through a portal to another area.
+    Travelling,
+    /// Objectives are done: the camera celebrates, then the results panel shows.
+    AreaComplete,
     ChooseArea,
 }
@@ -1,5 +1,3 @@
-use std::str::FromStr;
-
 use bevy::{
     app::{Plugin, PreUpdate, Update},
     asset::AssetServer,
@@ -10,27 +8,76 @@ use bevy::{
         schedule::IntoScheduleConfigs,
         system::{Commands, Query, Res, ResMut},
     },
-    input::{ButtonInput, keyboard::KeyCode, mouse::MouseButton},
+    input::{ButtonInput, mouse::MouseButton},
     input_focus::{InputFocus, directional_navigation::DirectionalNavigationMap},
     math::CompassOctant,
+    prelude::default,
     state::{
         condition::in_state,
         state::{NextState, OnEnter},
         state_scoped::DespawnOnExit,
     },
-    ui::{Interaction, widget::Button},
+    text::TextColor,
+    ui::{
+        AlignItems, BackgroundColor, BorderColor, FlexDirection, Interaction, JustifyContent,
+        Node, UiRect, Val, widget::Button,
+    },
 };
-use strum::IntoEnumIterator;
 
-use crate::{game::area::{Area, SelectedArea}, state::State, ui::common::spawn_camera};
+use crate::{
+    game::{
+        area::SelectedArea,
+        area_def::{AreaDef, AreaRegistry},
+        progress::format_time,
+    },
+    input::{Action, Actions},
+    save::{ActiveSave, AreaSave},
+    state::State,
+    ui::common::spawn_camera,
+};
 
 use super::common::{
-    BG_DARK, PANEL_BG, button_text, get_button_bundle, highlight_focused_element, navigate,
-    reset_button_after_interaction, spawn_divider, spawn_menu_root, spawn_panel, spawn_title,
+    BG_DARK, BORDER_LOCKED, BUTTON_LOCKED, PANEL_BG, TEXT_LOCKED, button_detail_text, button_text,
+    get_button_bundle, highlight_focused_element, navigate, reset_button_after_interaction,
+    spawn_divider, spawn_menu_root, spawn_panel, spawn_title,
 };
 
+/// Area buttons are taller than the rest to fit a progress line under the name.
+fn area_button_node() -> Node {
+    Node {
+        width: Val::Px(280.),
+        height: Val::Px(72.),
+        border: UiRect::all(Val::Px(2.0)),
+        flex_direction: FlexDirection::Column,
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        row_gap: Val::Px(2.0),
+        ..default()
+    }
+}
+
+/// Progress line for an unlocked area: completion, best time and secrets found.
+fn progress_line(def: &AreaDef, saved: Option<&AreaSave>) -> String {
+    let mut parts = Vec::new();
+    if !def.objectives.is_empty() {
+        let completed = saved.is_some_and(|a| a.completed);
+        parts.push(if completed { "Completed".to_string() } else { "Not completed".to_string() });
+    }
+    if let Some(best) = saved.and_then(|a| a.best_time) {
+        parts.push(format!("Best {}", format_time(best)));
+    }
+    let secrets = def.secret_count();
+    if secrets > 0 {
+        let found = saved.map_or(0, |a| a.secrets.len());
+        parts.push(format!("Secrets {found}/{secrets}"));
+    }
+    parts.join("  ·  ")
+}
+
 fn setup_ui(
     mut commands: Commands,
+    registry: Res<AreaRegistry>,
+    save: Res<ActiveSave>,
     mut directional_nav_map: ResMut<DirectionalNavigationMap>,
     mut input_focus: ResMut<InputFocus>,
     asset_server: Res<AssetServer>,
@@ -49,14 +96,50 @@ fn setup_ui(
 
     let mut button_entities: Vec<Entity> = Vec::new();
 
-    for area in Area::iter() {
-        let name: &'static str = area.into();
+    // Button names carry the area id; the label shows the display name and progress.
+    // Locked areas get a greyed-out panel instead of a button, so they can't be
+    // focused or clicked and are left out of the navigation map.
+    for (id, def) in registry.iter() {
+        if !save.is_unlocked(def) {
+            let required = def
+                .requires
+                .as_deref()
+                .and_then(|r| registry.get(r))
+                .map_or("another area", |r| r.name.as_str());
+            let entry = commands
+                .spawn((
+                    DespawnOnExit(State::ChooseArea),
+                    area_button_node(),
+                    BackgroundColor(BUTTON_LOCKED),
+                    BorderColor::all(BORDER_LOCKED),
+                    Name::new(id.to_string()),
+                ))
+                .with_children(|parent| {
+                    let hint = format!("Locked — complete {required}");
+                    parent
+                        .spawn(button_text(&def.name, body_font.clone()))
+                        .insert(TextColor(TEXT_LOCKED));
+                    parent
+                        .spawn(button_detail_text(&hint, body_font.clone()))
+                        .insert(TextColor(TEXT_LOCKED));
+                })
+                .id();
+            commands.entity(panel).add_child(entry);
+            continue;
+        }
+        let progress = progress_line(def, save.area(id));
         let button = commands
             .spawn((
                 DespawnOnExit(State::ChooseArea),
-                get_button_bundle(name.to_string()),
+                get_button_bundle(id.to_string()),
             ))
-            .with_child(button_text(name, body_font.clone()))
+            .insert(area_button_node())
+            .with_children(|parent| {
+                parent.spawn(button_text(&def.name, body_font.clone()));
+                if !progress.is_empty() {
+                    parent.spawn(button_detail_text(&progress, body_font.clone()));
+                }
+            })
             .id();
         commands.entity(panel).add_child(button);
         button_entities.push(button);
@@ -78,14 +161,19 @@ fn setup_ui(
 
 fn interact_with_focused_button(
     mut commands: Commands,
-    keyboard_input: Res<ButtonInput<KeyCode>>,
+    registry: Res<AreaRegistry>,
+    save: Res<ActiveSave>,
+    actions: Actions,
     mouse_input: Res<ButtonInput<MouseButton>>,
     input_focus: Res<InputFocus>,
     buttons: Query<(Entity, &Name, &Interaction), With<Button>>,
     mut next_state: ResMut<NextState<State>>,
 ) {
-    let key_pressed = keyboard_input.just_pressed(KeyCode::Space)
-        || keyboard_input.just_pressed(KeyCode::Enter);
+    if actions.just_pressed(Action::MenuBack) {
+        next_state.set(State::MainMenu);
+        return;
+    }
+    let key_pressed = actions.just_pressed(Action::MenuConfirm);
     let mouse_clicked = mouse_input.just_pressed(MouseButton::Left);
 
     for (entity, name, interaction) in &buttons {
@@ -94,15 +182,11 @@ fn interact_with_focused_button(
         if !activated {
             continue;
         }
-        match Area::from_str(name.as_str()) {
-            Ok(area) => {
-                commands.insert_resource(SelectedArea(area));
-                next_state.set(State::Playing);
-            }
-            _ if name.as_str() == "Main Menu" => {
-                next_state.set(State::MainMenu);
-            }
-            _ => (),
+        if registry.get(name.as_str()).is_some_and(|def| save.is_unlocked(def)) {
+            commands.insert_resource(SelectedArea(name.to_string()));
+            next_state.set(State::Playing);
+        } else if name.as_str() == "Main Menu" {
+            next_state.set(State::MainMenu);
         }
     }
 }
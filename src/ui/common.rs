@@ -10,7 +10,6 @@ use bevy::{
         query::With,
         system::{Commands, Query, Res, ResMut},
     },
-    input::{ButtonInput, keyboard::KeyCode},
     input_focus::{
         InputFocus, InputFocusVisible, directional_navigation::DirectionalNavigation,
         tab_navigation::TabIndex,
@@ -26,11 +25,16 @@ use bevy::{
         Interaction, JustifyContent, JustifyItems, Node, PositionType, UiRect, Val, percent, px,
         widget::{Button, Text},
     },
-    ui_widgets::{Slider, SliderRange, SliderThumb, SliderValue, TrackClick},
+    ui_widgets::{
+        Slider, SliderRange, SliderThumb, SliderValue, TrackClick, slider_self_update,
+    },
     utils::default,
 };
 
-use crate::state::State;
+use crate::{
+    input::{Action, Actions},
+    state::State,
+};
 
 // ─── Color Palette ────────────────────────────────────────────────────────────
 
@@ -45,6 +49,9 @@ pub const TEXT_TITLE: Color = Color::srgb(0.98, 0.85, 0.50);
 const SLIDER_TRACK_COLOR: Color = Color::srgb(0.14, 0.11, 0.05);
 const SLIDER_THUMB_COLOR: Color = Color::srgb(0.96, 0.75, 0.13);
 const TEXT_ON_FOCUSED: Color = Color::srgb(0.05, 0.04, 0.02);
+pub const BUTTON_LOCKED: Color = Color::srgba(0.08, 0.08, 0.10, 1.0);
+pub const BORDER_LOCKED: Color = Color::srgb(0.18, 0.17, 0.16);
+pub const TEXT_LOCKED: Color = Color::srgb(0.42, 0.40, 0.38);
 
 // ─── Camera ───────────────────────────────────────────────────────────────────
 
@@ -159,6 +166,15 @@ pub fn button_text(text: &str, font: Handle<Font>) -> (Text, TextFont, TextColor
     )
 }
 
+/// Smaller second line under a button label.
+pub fn button_detail_text(text: &str, font: Handle<Font>) -> (Text, TextFont, TextColor) {
+    (
+        Text::new(text),
+        TextFont { font, font_size: 14.0, ..default() },
+        TextColor(TEXT_PRIMARY),
+    )
+}
+
 // ─── Focus System ─────────────────────────────────────────────────────────────
 
 pub fn highlight_focused_element(
@@ -202,13 +218,17 @@ pub fn highlight_focused_element(
 // ─── Navigation ───────────────────────────────────────────────────────────────
 
 pub fn navigate(
-    keyboard_input: Res<ButtonInput<KeyCode>>,
+    actions: Actions,
     mut directional_navigation: DirectionalNavigation,
 ) {
-    if keyboard_input.just_pressed(KeyCode::ArrowUp) {
+    if actions.just_pressed(Action::MenuUp) {
         directional_navigation.navigate(CompassOctant::North).ok();
-    } else if keyboard_input.just_pressed(KeyCode::ArrowDown) {
+    } else if actions.just_pressed(Action::MenuDown) {
         directional_navigation.navigate(CompassOctant::South).ok();
+    } else if actions.just_pressed(Action::MenuLeft) {
+        directional_navigation.navigate(CompassOctant::West).ok();
+    } else if actions.just_pressed(Action::MenuRight) {
+        directional_navigation.navigate(CompassOctant::East).ok();
     }
 }
 
@@ -293,5 +313,6 @@ pub fn spawn_slider(
                     BackgroundColor(SLIDER_THUMB_COLOR),
                 ));
         })
+        .observe(slider_self_update)
         .id()
 }
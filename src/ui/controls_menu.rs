@@ -0,0 +1,264 @@
+use std::str::FromStr;
+
+use bevy::{
+    app::{Plugin, PreUpdate, Update},
+    asset::AssetServer,
+    ecs::{
+        change_detection::DetectChanges,
+        component::Component,
+        entity::Entity,
+        hierarchy::Children,
+        name::Name,
+        query::With,
+        resource::Resource,
+        schedule::IntoScheduleConfigs,
+        system::{Commands, Query, Res, ResMut},
+    },
+    input::{ButtonInput, keyboard::KeyCode, mouse::MouseButton},
+    input_focus::{InputFocus, directional_navigation::DirectionalNavigationMap},
+    math::CompassOctant,
+    prelude::{Color, SystemCondition},
+    state::{
+        condition::in_state,
+        state::{NextState, OnEnter},
+        state_scoped::DespawnOnExit,
+    },
+    text::{TextColor, TextFont},
+    ui::{
+        Display, Interaction, Node, RepeatedGridTrack, Val,
+        widget::{Button, Text},
+    },
+    utils::default,
+};
+use strum::IntoEnumIterator;
+
+use crate::{
+    input::{Action, Actions, key_label},
+    settings::AppSettings,
+    state::State,
+    ui::common::spawn_camera,
+};
+
+use super::common::{
+    BG_DARK, PANEL_BG, TEXT_PRIMARY, button_text, get_button_bundle, highlight_focused_element,
+    navigate, reset_button_after_interaction, spawn_divider, spawn_menu_root, spawn_panel,
+    spawn_title,
+};
+
+const BACK: &str = "Back";
+const TEXT_WARNING: Color = Color::srgb(0.95, 0.45, 0.35);
+
+/// The action waiting for its new key, if a rebind is in progress.
+#[derive(Resource, Default)]
+struct Rebinding(Option<Action>);
+
+/// Tag on the line of text under the bindings that reports conflicts.
+#[derive(Component)]
+struct ControlsStatus;
+
+fn label_for(action: Action, settings: &AppSettings, rebinding: &Rebinding) -> String {
+    let name: &'static str = action.into();
+    if rebinding.0 == Some(action) {
+        return format!("{name}: press a key…");
+    }
+    let keys: Vec<String> = settings.bindings.keys(action).into_iter().map(key_label).collect();
+    format!("{name}: {}", keys.join(" / "))
+}
+
+fn setup_ui(
+    mut commands: Commands,
+    settings: Res<AppSettings>,
+    mut rebinding: ResMut<Rebinding>,
+    mut directional_nav_map: ResMut<DirectionalNavigationMap>,
+    mut input_focus: ResMut<InputFocus>,
+    asset_server: Res<AssetServer>,
+) {
+    let title_font = asset_server.load("fonts/Cinzel-Regular.ttf");
+    let body_font = asset_server.load("fonts/Nunito-Regular.ttf");
+    rebinding.0 = None;
+
+    let root = spawn_menu_root(&mut commands, State::ControlsMenu, BG_DARK);
+    let panel = spawn_panel(&mut commands, State::ControlsMenu, PANEL_BG);
+    commands.entity(root).add_child(panel);
+
+    let title = spawn_title(&mut commands, "Controls", State::ControlsMenu, title_font);
+    let divider = spawn_divider(&mut commands, State::ControlsMenu);
+    commands.entity(panel).add_child(title);
+    commands.entity(panel).add_child(divider);
+
+    // Two columns so every action fits on screen
+    let grid = commands
+        .spawn((
+            DespawnOnExit(State::ControlsMenu),
+            Node {
+                display: Display::Grid,
+                grid_template_columns: RepeatedGridTrack::auto(2),
+                row_gap: Val::Px(10.0),
+                column_gap: Val::Px(16.0),
+                ..default()
+            },
+        ))
+        .id();
+    commands.entity(panel).add_child(grid);
+
+    let mut button_entities: Vec<Entity> = Vec::new();
+    for action in Action::iter() {
+        let name: &'static str = action.into();
+        let label = label_for(action, &settings, &rebinding);
+        let button = commands
+            .spawn((
+                DespawnOnExit(State::ControlsMenu),
+                get_button_bundle(name.into()),
+            ))
+            .with_child(button_text(&label, body_font.clone()))
+            .id();
+        commands.entity(grid).add_child(button);
+        button_entities.push(button);
+    }
+
+    let status = commands
+        .spawn((
+            DespawnOnExit(State::ControlsMenu),
+            ControlsStatus,
+            Text::new(""),
+            TextFont { font: body_font.clone(), font_size: 18.0, ..default() },
+            TextColor(TEXT_WARNING),
+        ))
+        .id();
+    commands.entity(panel).add_child(status);
+
+    let back = commands
+        .spawn((
+            DespawnOnExit(State::ControlsMenu),
+            get_button_bundle(BACK.into()),
+        ))
+        .with_child(button_text(BACK, body_font))
+        .id();
+    commands.entity(panel).add_child(back);
+    button_entities.push(back);
+
+    directional_nav_map.add_looping_edges(&button_entities, CompassOctant::South);
+    input_focus.set(button_entities[0]);
+}
+
+fn not_rebinding(rebinding: Res<Rebinding>) -> bool {
+    rebinding.0.is_none()
+}
+
+fn interact_with_focused_button(
+    actions: Actions,
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    input_focus: Res<InputFocus>,
+    buttons: Query<(Entity, &Name, &Interaction), With<Button>>,
+    mut rebinding: ResMut<Rebinding>,
+    mut statuses: Query<&mut Text, With<ControlsStatus>>,
+    mut next_state: ResMut<NextState<State>>,
+) {
+    if actions.just_pressed(Action::MenuBack) {
+        next_state.set(State::OptionsMenu);
+        return;
+    }
+    let key_pressed = actions.just_pressed(Action::MenuConfirm);
+    let mouse_clicked = mouse_input.just_pressed(MouseButton::Left);
+
+    for (entity, name, interaction) in &buttons {
+        let activated = (key_pressed && input_focus.0 == Some(entity))
+            || (mouse_clicked && *interaction == Interaction::Pressed);
+        if !activated {
+            continue;
+        }
+        if let Ok(action) = Action::from_str(name.as_str()) {
+            rebinding.0 = Some(action);
+            for mut text in &mut statuses {
+                **text = String::new();
+            }
+        } else if name.as_str() == BACK {
+            next_state.set(State::OptionsMenu);
+        }
+    }
+}
+
+/// While a rebind is pending, the next key pressed becomes the action's binding.
+/// Escape cancels; keys already used by another action are refused.
+fn capture_rebind(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut rebinding: ResMut<Rebinding>,
+    mut settings: ResMut<AppSettings>,
+    mut statuses: Query<&mut Text, With<ControlsStatus>>,
+) {
+    // Skip the frame the rebind started so the confirm key isn't captured
+    if rebinding.is_changed() {
+        return;
+    }
+    let Some(action) = rebinding.0 else { return };
+    let Some(&key) = keyboard_input.get_just_pressed().next() else { return };
+    rebinding.0 = None;
+    if key == KeyCode::Escape {
+        return;
+    }
+    let mut bindings = settings.bindings.clone();
+    let message = match bindings.rebind(action, key) {
+        Ok(()) => {
+            settings.bindings = bindings;
+            String::new()
+        }
+        Err(other) => {
+            let other: &'static str = other.into();
+            format!("{} is already bound to {other}", key_label(key))
+        }
+    };
+    for mut text in &mut statuses {
+        **text = message.clone();
+    }
+}
+
+/// Keep binding labels in sync with the settings and the pending rebind.
+fn update_binding_labels(
+    settings: Res<AppSettings>,
+    rebinding: Res<Rebinding>,
+    buttons: Query<(&Name, &Children)>,
+    text_entities: Query<Entity, With<Text>>,
+    mut commands: Commands,
+) {
+    if !settings.is_changed() && !rebinding.is_changed() {
+        return;
+    }
+    for (name, children) in &buttons {
+        let Ok(action) = Action::from_str(name.as_str()) else { continue };
+        let label = label_for(action, &settings, &rebinding);
+        for &child in children.iter() {
+            if text_entities.get(child).is_ok() {
+                commands.entity(child).insert((
+                    Text::new(label.clone()),
+                    TextColor(TEXT_PRIMARY),
+                ));
+            }
+        }
+    }
+}
+
+pub struct ControlsPlugin;
+impl Plugin for ControlsPlugin {
+    fn build(&self, app: &mut bevy::app::App) {
+        app.init_resource::<Rebinding>()
+            .add_systems(
+                OnEnter(State::ControlsMenu),
+                (spawn_camera, setup_ui.after(spawn_camera)),
+            )
+            .add_systems(
+                PreUpdate,
+                navigate.run_if(in_state(State::ControlsMenu).and(not_rebinding)),
+            )
+            .add_systems(
+                Update,
+                (
+                    highlight_focused_element,
+                    interact_with_focused_button.run_if(not_rebinding),
+                    capture_rebind.after(interact_with_focused_button),
+                    reset_button_after_interaction,
+                    update_binding_labels,
+                )
+                    .run_if(in_state(State::ControlsMenu)),
+            );
+    }
+}
@@ -4,14 +4,16 @@ use bevy::{
     app::{AppExit, Plugin, PreUpdate, Update},
     asset::AssetServer,
     ecs::{
+        change_detection::DetectChanges,
         entity::Entity,
+        hierarchy::Children,
         message::MessageWriter,
         name::Name,
         query::With,
         schedule::IntoScheduleConfigs,
         system::{Commands, Query, Res, ResMut},
     },
-    input::{ButtonInput, keyboard::KeyCode, mouse::MouseButton},
+    input::{ButtonInput, mouse::MouseButton},
     input_focus::{
         InputDispatchPlugin, InputFocus, InputFocusVisible,
         directional_navigation::{DirectionalNavigationMap, DirectionalNavigationPlugin},
@@ -22,14 +24,21 @@ use bevy::{
         state::{NextState, OnEnter, States},
         state_scoped::DespawnOnExit,
     },
-    ui::{Interaction, widget::Button},
+    text::TextColor,
+    ui::{Interaction, widget::{Button, Text}},
 };
 use strum::{EnumCount, EnumIter, EnumString, IntoEnumIterator, IntoStaticStr};
 
-use crate::{state::State, ui::common::spawn_camera};
+use crate::{
+    input::{Action, Actions},
+    save::SAVE_SLOTS,
+    settings::AppSettings,
+    state::State,
+    ui::common::spawn_camera,
+};
 
 use super::common::{
-    BG_DARK, PANEL_BG, button_text, get_button_bundle, highlight_focused_element, navigate,
+    BG_DARK, PANEL_BG, TEXT_PRIMARY, button_text, get_button_bundle, highlight_focused_element, navigate,
     reset_button_after_interaction, spawn_divider, spawn_menu_root, spawn_panel, spawn_title,
 };
 
@@ -52,12 +61,22 @@ pub enum MainMenuEnum {
     Play,
     #[strum(to_string = "Choose Area")]
     ChooseArea,
+    #[strum(to_string = "Save Slot")]
+    SaveSlot,
     Options,
     Exit,
 }
 
+fn label_for(option: MainMenuEnum, settings: &AppSettings) -> String {
+    match option {
+        MainMenuEnum::SaveSlot => format!("Save Slot: {}", settings.save_slot),
+        other => <&'static str>::from(other).to_string(),
+    }
+}
+
 fn setup_ui(
     mut commands: Commands,
+    settings: Res<AppSettings>,
     mut directional_nav_map: ResMut<DirectionalNavigationMap>,
     mut input_focus: ResMut<InputFocus>,
     asset_server: Res<AssetServer>,
@@ -77,12 +96,13 @@ fn setup_ui(
     let mut button_entities: Vec<Entity> = Vec::new();
     for option in MainMenuEnum::iter() {
         let name: &'static str = option.into();
+        let label = label_for(option, &settings);
         let button_entity = commands
             .spawn((
                 DespawnOnExit(State::MainMenu),
                 get_button_bundle(name.into()),
             ))
-            .with_child(button_text(name, body_font.clone()))
+            .with_child(button_text(&label, body_font.clone()))
             .id();
         commands.entity(panel).add_child(button_entity);
         button_entities.push(button_entity);
@@ -93,15 +113,15 @@ fn setup_ui(
 }
 
 fn interact_with_focused_button(
-    keyboard_input: Res<ButtonInput<KeyCode>>,
+    actions: Actions,
     mouse_input: Res<ButtonInput<MouseButton>>,
     input_focus: Res<InputFocus>,
     buttons: Query<(Entity, &Name, &Interaction), With<Button>>,
     mut exit: MessageWriter<AppExit>,
     mut next_state: ResMut<NextState<State>>,
+    mut settings: ResMut<AppSettings>,
 ) {
-    let key_pressed = keyboard_input.just_pressed(KeyCode::Space)
-        || keyboard_input.just_pressed(KeyCode::Enter);
+    let key_pressed = actions.just_pressed(Action::MenuConfirm);
     let mouse_clicked = mouse_input.just_pressed(MouseButton::Left);
 
     for (entity, name, interaction) in &buttons {
@@ -117,6 +137,9 @@ fn interact_with_focused_button(
             Ok(MainMenuEnum::ChooseArea) => {
                 next_state.set(State::ChooseArea);
             }
+            Ok(MainMenuEnum::SaveSlot) => {
+                settings.save_slot = settings.save_slot % SAVE_SLOTS + 1;
+            }
             Ok(MainMenuEnum::Options) => {
                 next_state.set(State::OptionsMenu);
             }
@@ -128,6 +151,32 @@ fn interact_with_focused_button(
     }
 }
 
+/// Keep the save slot label in sync with the current settings value.
+fn update_slot_label(
+    settings: Res<AppSettings>,
+    buttons: Query<(&Name, &Children)>,
+    text_entities: Query<Entity, With<Text>>,
+    mut commands: Commands,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+    for (name, children) in &buttons {
+        if !matches!(MainMenuEnum::from_str(name.as_str()), Ok(MainMenuEnum::SaveSlot)) {
+            continue;
+        }
+        let label = label_for(MainMenuEnum::SaveSlot, &settings);
+        for &child in children.iter() {
+            if text_entities.get(child).is_ok() {
+                commands.entity(child).insert((
+                    Text::new(label.clone()),
+                    TextColor(TEXT_PRIMARY),
+                ));
+            }
+        }
+    }
+}
+
 pub struct MainMenuPlugin;
 impl Plugin for MainMenuPlugin {
     fn build(&self, app: &mut bevy::app::App) {
@@ -144,6 +193,7 @@ impl Plugin for MainMenuPlugin {
                     highlight_focused_element,
                     interact_with_focused_button,
                     reset_button_after_interaction,
+                    update_slot_label,
                 )
                     .run_if(in_state(State::MainMenu)),
             );
@@ -1,10 +1,14 @@
 pub(crate) mod areas_menu;
 pub(crate) mod common;
+mod controls_menu;
 mod main_menu;
 mod options_menu;
 mod paused_menu;
+mod results_menu;
 
 pub use areas_menu::AreasMenuPlugin;
+pub use controls_menu::ControlsPlugin;
 pub use main_menu::MainMenuPlugin;
 pub use options_menu::OptionsPlugin;
 pub use paused_menu::PausedPlugin;
+pub use results_menu::ResultsPlugin;
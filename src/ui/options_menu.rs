@@ -13,7 +13,7 @@ use bevy::{
         schedule::IntoScheduleConfigs,
         system::{Commands, Query, Res, ResMut},
     },
-    input::{ButtonInput, keyboard::KeyCode, mouse::MouseButton},
+    input::{ButtonInput, mouse::MouseButton},
     input_focus::{InputFocus, directional_navigation::DirectionalNavigationMap},
     math::CompassOctant,
     state::{
@@ -21,23 +21,28 @@ use bevy::{
         state::{NextState, OnEnter, States},
         state_scoped::DespawnOnExit,
     },
+    prelude::default,
     text::TextColor,
-    ui::{Interaction, widget::{Button, Text}},
+    ui::{AlignItems, FlexDirection, Interaction, Node, Val, widget::{Button, Text}},
 };
 use strum::{EnumCount, EnumIter, EnumString, IntoEnumIterator, IntoStaticStr};
 
 use crate::{
-    settings::AppSettings,
+    input::{Action, Actions},
+    settings::{AppSettings, AudioBus},
     state::State,
     ui::common::{spawn_camera, spawn_slider},
 };
 
 use super::common::{
-    BG_DARK, PANEL_BG, TEXT_PRIMARY, button_text, get_button_bundle, highlight_focused_element,
-    navigate, reset_button_after_interaction, spawn_divider, spawn_menu_root, spawn_panel,
-    spawn_title,
+    BG_DARK, PANEL_BG, TEXT_PRIMARY, button_detail_text, button_text, get_button_bundle,
+    highlight_focused_element, navigate, reset_button_after_interaction, spawn_divider,
+    spawn_menu_root, spawn_panel, spawn_title,
 };
 
+/// Volume points one d-pad press moves a slider by.
+const SLIDER_STEP: f32 = 5.0;
+
 #[derive(Component)]
 pub struct Options;
 
@@ -60,11 +65,35 @@ pub enum OptionsEnum {
     Fullscreen,
     #[strum(to_string = "Invert Mouse")]
     InvertMouse,
-    Volume,
+    #[strum(to_string = "Master Volume")]
+    MasterVolume,
+    #[strum(to_string = "Music Volume")]
+    MusicVolume,
+    #[strum(to_string = "Ambience Volume")]
+    AmbienceVolume,
+    #[strum(to_string = "Effects Volume")]
+    SfxVolume,
+    #[strum(to_string = "Interface Volume")]
+    UiVolume,
+    Controls,
     #[strum(to_string = "Main Menu")]
     MainMenu,
 }
 
+impl OptionsEnum {
+    /// The mixer bus a volume slider controls; `None` for buttons.
+    fn bus(self) -> Option<AudioBus> {
+        match self {
+            OptionsEnum::MasterVolume => Some(AudioBus::Master),
+            OptionsEnum::MusicVolume => Some(AudioBus::Music),
+            OptionsEnum::AmbienceVolume => Some(AudioBus::Ambience),
+            OptionsEnum::SfxVolume => Some(AudioBus::Sfx),
+            OptionsEnum::UiVolume => Some(AudioBus::Ui),
+            _ => None,
+        }
+    }
+}
+
 fn label_for(option: OptionsEnum, settings: &AppSettings) -> String {
     match option {
         OptionsEnum::Fullscreen => format!(
@@ -75,8 +104,7 @@ fn label_for(option: OptionsEnum, settings: &AppSettings) -> String {
             "Invert Mouse: {}",
             if settings.invert_mouse { "ON" } else { "OFF" }
         ),
-        OptionsEnum::Volume => "Volume".to_string(),
-        OptionsEnum::MainMenu => "Main Menu".to_string(),
+        other => <&'static str>::from(other).to_string(),
     }
 }
 
@@ -103,15 +131,26 @@ fn setup_ui(
     let mut button_entities: Vec<Entity> = Vec::new();
     for option in OptionsEnum::iter() {
         let name: &'static str = option.into();
-        if option == OptionsEnum::Volume {
+        if let Some(bus) = option.bus() {
             let slider = spawn_slider(
                 &mut commands,
                 name.into(),
-                settings.volume * 100.0,
+                settings.bus_volume(bus) * 100.0,
                 0.,
                 100.,
             );
-            commands.entity(panel).add_child(slider);
+            // Sliders have no text of their own, so each sits under a label
+            let row = commands
+                .spawn(Node {
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(6.0),
+                    ..default()
+                })
+                .with_child(button_detail_text(name, body_font.clone()))
+                .add_child(slider)
+                .id();
+            commands.entity(panel).add_child(row);
             button_entities.push(slider);
         } else {
             let label = label_for(option, &settings);
@@ -131,15 +170,18 @@ fn setup_ui(
 }
 
 fn interact_with_focused_button(
-    keyboard_input: Res<ButtonInput<KeyCode>>,
+    actions: Actions,
     mouse_input: Res<ButtonInput<MouseButton>>,
     input_focus: Res<InputFocus>,
     buttons: Query<(Entity, &Name, &Interaction), With<Button>>,
     mut next_state: ResMut<NextState<State>>,
     mut settings: ResMut<AppSettings>,
 ) {
-    let key_pressed = keyboard_input.just_pressed(KeyCode::Space)
-        || keyboard_input.just_pressed(KeyCode::Enter);
+    if actions.just_pressed(Action::MenuBack) {
+        next_state.set(State::MainMenu);
+        return;
+    }
+    let key_pressed = actions.just_pressed(Action::MenuConfirm);
     let mouse_clicked = mouse_input.just_pressed(MouseButton::Left);
 
     for (entity, name, interaction) in &buttons {
@@ -155,6 +197,9 @@ fn interact_with_focused_button(
             Ok(OptionsEnum::InvertMouse) => {
                 settings.invert_mouse = !settings.invert_mouse;
             }
+            Ok(OptionsEnum::Controls) => {
+                next_state.set(State::ControlsMenu);
+            }
             Ok(OptionsEnum::MainMenu) => {
                 next_state.set(State::MainMenu);
             }
@@ -193,18 +238,40 @@ fn update_toggle_labels(
     }
 }
 
-/// Sync the Volume slider value → AppSettings → GlobalVolume.
-fn sync_volume_slider(
+/// Sync the volume sliders → AppSettings, which the audio plugin applies to every bus.
+fn sync_volume_sliders(
     sliders: Query<(&Name, &bevy::ui_widgets::SliderValue), bevy::ecs::query::Changed<bevy::ui_widgets::SliderValue>>,
     mut settings: ResMut<AppSettings>,
 ) {
     for (name, slider_value) in &sliders {
-        if name.as_str() == "Volume" {
-            settings.volume = (slider_value.0 / 100.0).clamp(0.0, 1.0);
+        if let Some(bus) = OptionsEnum::from_str(name.as_str()).ok().and_then(OptionsEnum::bus) {
+            settings.set_bus_volume(bus, slider_value.0 / 100.0);
         }
     }
 }
 
+/// Nudges the focused volume slider from the d-pad. The slider widget reads the
+/// arrow keys itself, so only the gamepad is handled here.
+fn step_focused_slider(
+    mut commands: Commands,
+    actions: Actions,
+    focus: Res<InputFocus>,
+    sliders: Query<(), With<bevy::ui_widgets::Slider>>,
+) {
+    let Some(entity) = focus.0.filter(|&e| sliders.contains(e)) else { return };
+    let step = if actions.gamepad_just_pressed(Action::MenuRight) {
+        SLIDER_STEP
+    } else if actions.gamepad_just_pressed(Action::MenuLeft) {
+        -SLIDER_STEP
+    } else {
+        return;
+    };
+    commands.trigger(bevy::ui_widgets::SetSliderValue {
+        entity,
+        change: bevy::ui_widgets::SliderValueChange::Relative(step),
+    });
+}
+
 pub struct OptionsPlugin;
 impl Plugin for OptionsPlugin {
     fn build(&self, app: &mut bevy::app::App) {
@@ -220,7 +287,8 @@ impl Plugin for OptionsPlugin {
                 interact_with_focused_button,
                 reset_button_after_interaction,
                 update_toggle_labels,
-                sync_volume_slider,
+                step_focused_slider,
+                sync_volume_sliders.after(step_focused_slider),
             )
                 .run_if(in_state(State::OptionsMenu)),
         );
@@ -11,7 +11,7 @@ use bevy::{
         schedule::IntoScheduleConfigs,
         system::{Commands, Query, Res, ResMut},
     },
-    input::{ButtonInput, keyboard::KeyCode, mouse::MouseButton},
+    input::{ButtonInput, mouse::MouseButton},
     input_focus::{InputFocus, directional_navigation::DirectionalNavigationMap},
     math::CompassOctant,
     state::{
@@ -23,7 +23,10 @@ use bevy::{
 };
 use strum::{EnumCount, EnumIter, EnumString, IntoEnumIterator, IntoStaticStr};
 
-use crate::state::State;
+use crate::{
+    input::{Action, Actions},
+    state::State,
+};
 
 use super::common::{
     button_text, get_button_bundle, highlight_focused_element, navigate,
@@ -96,14 +99,13 @@ fn setup_ui(
 }
 
 fn interact_with_focused_button(
-    keyboard_input: Res<ButtonInput<KeyCode>>,
+    actions: Actions,
     mouse_input: Res<ButtonInput<MouseButton>>,
     input_focus: Res<InputFocus>,
     buttons: Query<(Entity, &Name, &Interaction), With<Button>>,
     mut next_state: ResMut<NextState<State>>,
 ) {
-    let key_pressed = keyboard_input.just_pressed(KeyCode::Space)
-        || keyboard_input.just_pressed(KeyCode::Enter);
+    let key_pressed = actions.just_pressed(Action::MenuConfirm);
     let mouse_clicked = mouse_input.just_pressed(MouseButton::Left);
 
     for (entity, name, interaction) in &buttons {
@@ -124,11 +126,11 @@ fn interact_with_focused_button(
     }
 }
 
-fn resume_on_escape(
-    keyboard_input: Res<ButtonInput<KeyCode>>,
+fn resume_on_back(
+    actions: Actions,
     mut next_state: ResMut<NextState<State>>,
 ) {
-    if keyboard_input.just_pressed(KeyCode::Escape) {
+    if actions.just_pressed(Action::Pause) || actions.just_pressed(Action::MenuBack) {
         next_state.set(State::Playing);
     }
 }
@@ -144,7 +146,7 @@ impl Plugin for PausedPlugin {
                     highlight_focused_element,
                     interact_with_focused_button,
                     reset_button_after_interaction,
-                    resume_on_escape,
+                    resume_on_back,
                 )
                     .run_if(in_state(State::Paused)),
             );
@@ -0,0 +1,302 @@
+use std::str::FromStr;
+
+use bevy::{
+    app::{Plugin, PreUpdate, Update},
+    asset::AssetServer,
+    color::Color,
+    ecs::{
+        component::Component,
+        entity::Entity,
+        name::Name,
+        query::With,
+        schedule::IntoScheduleConfigs,
+        system::{Commands, Query, Res, ResMut},
+    },
+    input::{ButtonInput, mouse::MouseButton},
+    input_focus::{InputFocus, directional_navigation::DirectionalNavigationMap},
+    math::CompassOctant,
+    state::{
+        condition::in_state,
+        state::NextState,
+        state_scoped::DespawnOnExit,
+    },
+    ui::{Interaction, widget::Button},
+};
+use strum::{EnumCount, EnumIter, EnumString, IntoEnumIterator, IntoStaticStr};
+
+use crate::{
+    audio::AmbientBed,
+    game::{
+        area::{GameEntity, SelectedArea, selected_def},
+        area_def::AreaRegistry,
+        camera::Celebration,
+        progress::format_time,
+    },
+    input::{Action, Actions},
+    save::ActiveSave,
+    state::State,
+};
+
+use super::common::{
+    button_text, get_button_bundle, highlight_focused_element, navigate,
+    reset_button_after_interaction, spawn_divider, spawn_menu_root, spawn_panel, spawn_title,
+};
+
+#[derive(
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Debug,
+    Default,
+    EnumIter,
+    IntoStaticStr,
+    EnumCount,
+    EnumString,
+)]
+pub enum ResultsMenuEnum {
+    #[default]
+    Replay,
+    #[strum(to_string = "Next Area")]
+    NextArea,
+    #[strum(to_string = "Main Menu")]
+    MainMenu,
+}
+
+/// Tag on the results overlay, so it is only built once per completion.
+#[derive(Component)]
+struct ResultsRoot;
+
+/// The area offered by Next Area: the first unlocked area after `current` in
+/// menu order, wrapping around, preferring ones with objectives not completed
+/// yet, then any with objectives. Areas with nothing to complete, like the hub,
+/// are only offered when nothing else is open.
+fn next_area(registry: &AreaRegistry, save: &ActiveSave, current: &str) -> Option<String> {
+    let areas: Vec<_> = registry.iter().collect();
+    let start = areas.iter().position(|(id, _)| *id == current)?;
+    let candidates: Vec<_> = areas
+        .iter()
+        .cycle()
+        .skip(start + 1)
+        .take(areas.len() - 1)
+        .filter(|(_, def)| save.is_unlocked(def))
+        .collect();
+    let completed = |id: &str| save.area(id).is_some_and(|a| a.completed);
+    candidates
+        .iter()
+        .find(|(id, def)| !def.objectives.is_empty() && !completed(id))
+        .or_else(|| candidates.iter().find(|(_, def)| !def.objectives.is_empty()))
+        .or(candidates.first())
+        .map(|(id, _)| id.to_string())
+}
+
+/// Builds the results panel once the celebratory camera swing has finished.
+fn setup_ui(
+    mut commands: Commands,
+    celebration: Res<Celebration>,
+    existing: Query<(), With<ResultsRoot>>,
+    selected: Res<SelectedArea>,
+    registry: Res<AreaRegistry>,
+    save: Res<ActiveSave>,
+    mut directional_nav_map: ResMut<DirectionalNavigationMap>,
+    mut input_focus: ResMut<InputFocus>,
+    asset_server: Res<AssetServer>,
+) {
+    if !celebration.0.is_finished() || !existing.is_empty() {
+        return;
+    }
+    let title_font = asset_server.load("fonts/Cinzel-Regular.ttf");
+    let body_font = asset_server.load("fonts/Nunito-Regular.ttf");
+
+    // Light overlay so the lit-up area stays visible behind the panel
+    let root = spawn_menu_root(
+        &mut commands,
+        State::AreaComplete,
+        Color::srgba(0.02, 0.02, 0.05, 0.45),
+    );
+    commands.entity(root).insert(ResultsRoot);
+    let panel = spawn_panel(
+        &mut commands,
+        State::AreaComplete,
+        Color::srgba(0.04, 0.04, 0.09, 0.85),
+    );
+    commands.entity(root).add_child(panel);
+
+    let def = selected_def(&registry, &selected);
+    let heading = def
+        .and_then(|d| d.hud.as_ref())
+        .map_or("Area Complete", |hud| hud.win_text.as_str());
+    let title = spawn_title(&mut commands, heading, State::AreaComplete, title_font);
+    let divider = spawn_divider(&mut commands, State::AreaComplete);
+    commands.entity(panel).add_child(title);
+    commands.entity(panel).add_child(divider);
+
+    // Stats for the run just finished
+    let saved = save.area(&selected.0);
+    if let Some(result) = saved.and_then(|a| a.results.last()) {
+        let mut lines = Vec::new();
+        let best = saved.and_then(|a| a.best_time) == Some(result.time);
+        lines.push(if best {
+            format!("Time  {}  ·  New best!", format_time(result.time))
+        } else {
+            format!("Time  {}", format_time(result.time))
+        });
+        lines.push(format!("Interactions  {}", result.interactions));
+        let secrets = def.map_or(0, |d| d.secret_count());
+        if secrets > 0 {
+            lines.push(format!("Secrets found  {}/{secrets}", result.secrets));
+        }
+        for line in lines {
+            let text = commands
+                .spawn((DespawnOnExit(State::AreaComplete), button_text(&line, body_font.clone())))
+                .id();
+            commands.entity(panel).add_child(text);
+        }
+    }
+
+    // Next Area only shows when there is somewhere else to go
+    let has_next = next_area(&registry, &save, &selected.0).is_some();
+    let mut button_entities = Vec::new();
+    for item in ResultsMenuEnum::iter() {
+        if item == ResultsMenuEnum::NextArea && !has_next {
+            continue;
+        }
+        let name: &'static str = item.into();
+        let button = commands
+            .spawn((
+                DespawnOnExit(State::AreaComplete),
+                get_button_bundle(name.to_string()),
+            ))
+            .with_child(button_text(name, body_font.clone()))
+            .id();
+        commands.entity(panel).add_child(button);
+        button_entities.push(button);
+    }
+
+    directional_nav_map.add_looping_edges(&button_entities, CompassOctant::South);
+    input_focus.set(button_entities[0]);
+}
+
+fn interact_with_focused_button(
+    mut commands: Commands,
+    actions: Actions,
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    input_focus: Res<InputFocus>,
+    buttons: Query<(Entity, &Name, &Interaction), With<Button>>,
+    selected: Res<SelectedArea>,
+    registry: Res<AreaRegistry>,
+    mut save: ResMut<ActiveSave>,
+    world: Query<Entity, (With<GameEntity>, Without<AmbientBed>)>,
+    mut next_state: ResMut<NextState<State>>,
+) {
+    let key_pressed = actions.just_pressed(Action::MenuConfirm);
+    let mouse_clicked = mouse_input.just_pressed(MouseButton::Left);
+
+    for (entity, name, interaction) in &buttons {
+        let activated = (key_pressed && input_focus.0 == Some(entity))
+            || (mouse_clicked && *interaction == Interaction::Pressed);
+        if !activated {
+            continue;
+        }
+        // Replay and Next Area unload the whole world, cat and camera included,
+        // so play starts from the save as it would from the area menu. The
+        // ambience stays: Replay keeps it, Next Area crossfades it.
+        match ResultsMenuEnum::from_str(name.as_str()) {
+            Ok(ResultsMenuEnum::Replay) => {
+                save.area_mut(&selected.0).restart();
+                save.save();
+                for entity in &world {
+                    commands.entity(entity).despawn();
+                }
+                next_state.set(State::Playing);
+            }
+            Ok(ResultsMenuEnum::NextArea) => {
+                let Some(next) = next_area(&registry, &save, &selected.0) else { continue };
+                for entity in &world {
+                    commands.entity(entity).despawn();
+                }
+                commands.insert_resource(SelectedArea(next));
+                next_state.set(State::Playing);
+            }
+            Ok(ResultsMenuEnum::MainMenu) => {
+                next_state.set(State::MainMenu);
+            }
+            _ => (),
+        }
+    }
+}
+
+pub struct ResultsPlugin;
+impl Plugin for ResultsPlugin {
+    fn build(&self, app: &mut bevy::app::App) {
+        app.add_systems(PreUpdate, navigate.run_if(in_state(State::AreaComplete)))
+            .add_systems(
+                Update,
+                (
+                    setup_ui,
+                    highlight_focused_element,
+                    interact_with_focused_button,
+                    reset_button_after_interaction,
+                )
+                    .run_if(in_state(State::AreaComplete)),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::area_def::AreaDef;
+
+    fn area(requires: Option<&str>, objectives: &str) -> AreaDef {
+        let requires = requires.map_or("None".to_string(), |id| format!("Some({id:?})"));
+        ron::from_str(&format!(
+            "AreaDef(
+                name: \"Test\",
+                requires: {requires},
+                bounds: (play: Circle(3.0), camera: Circle(5.0)),
+                floor: (radius: 4.0, color: (0.1, 0.1, 0.1), noise_scale: 1.0),
+                objectives: [{objectives}],
+            )"
+        ))
+        .unwrap()
+    }
+
+    /// The shipped layout: two caves with embers and crystals, and a hub between them.
+    fn registry() -> AreaRegistry {
+        let embers = "(label: \"Embers\", goal: Light(kind: Ember))";
+        AreaRegistry::from_areas(vec![
+            ("cave".to_string(), area(None, embers)),
+            ("crystal_cavern".to_string(), area(Some("cave"), embers)),
+            ("hub".to_string(), area(None, "")),
+        ])
+    }
+
+    fn complete(save: &mut ActiveSave, id: &str) {
+        save.area_mut(id).completed = true;
+    }
+
+    #[test]
+    fn prefers_an_open_area_not_completed_yet() {
+        let mut save = ActiveSave::default();
+        complete(&mut save, "cave");
+        assert_eq!(next_area(&registry(), &save, "cave").as_deref(), Some("crystal_cavern"));
+        assert_eq!(next_area(&registry(), &save, "hub").as_deref(), Some("crystal_cavern"));
+    }
+
+    #[test]
+    fn never_settles_on_the_hub_once_everything_is_complete() {
+        let mut save = ActiveSave::default();
+        complete(&mut save, "cave");
+        complete(&mut save, "crystal_cavern");
+        assert_eq!(next_area(&registry(), &save, "cave").as_deref(), Some("crystal_cavern"));
+        assert_eq!(next_area(&registry(), &save, "crystal_cavern").as_deref(), Some("cave"));
+    }
+
+    #[test]
+    fn locked_areas_are_not_offered() {
+        let save = ActiveSave::default();
+        assert_eq!(next_area(&registry(), &save, "cave").as_deref(), Some("hub"));
+    }
+}